@@ -0,0 +1,76 @@
+//! Block authoring that respects the validators disabled by the runtime.
+
+use crate::service::FullClient;
+use futures::future::{self, Either};
+use node_template_runtime::opaque::Block;
+use pallet_validator_set_runtime_api::ValidatorSetApi;
+use sp_api::ProvideRuntimeApi;
+use sp_consensus::{Environment, Error as ConsensusError};
+use sp_consensus_aura::AuraApi;
+use sp_core::crypto::{key_types::AURA, ByteArray};
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::sync::Arc;
+
+/// A proposer factory that refuses to build blocks while the Aura keys of this node are disabled.
+///
+/// Disabled validators keep their Aura slots until the session ends, but the runtime rejects
+/// blocks they author, so proposing one only burns the slot's authoring time on a block that is
+/// thrown away. The disabled validators are read through `ValidatorSetApi` at the parent block;
+/// their indices are the ones of the Aura authorities.
+pub struct SkipWhenDisabled<PF> {
+	inner: PF,
+	client: Arc<FullClient>,
+	keystore: KeystorePtr,
+}
+
+impl<PF> SkipWhenDisabled<PF> {
+	/// Wrap `inner`, checking the Aura keys held by `keystore`.
+	pub fn new(inner: PF, client: Arc<FullClient>, keystore: KeystorePtr) -> Self {
+		Self { inner, client, keystore }
+	}
+
+	/// Whether this node holds Aura authority keys at `parent`, all of them disabled.
+	fn local_authorities_disabled(&self, parent: <Block as BlockT>::Hash) -> bool {
+		let api = self.client.runtime_api();
+		let authorities = match api.authorities(parent) {
+			Ok(authorities) => authorities,
+			Err(_) => return false,
+		};
+		let local: Vec<u32> = authorities
+			.iter()
+			.enumerate()
+			.filter(|(_, authority)| self.keystore.has_keys(&[(authority.to_raw_vec(), AURA)]))
+			.map(|(index, _)| index as u32)
+			.collect();
+		if local.is_empty() {
+			return false
+		}
+
+		match api.disabled_validators(parent) {
+			Ok(disabled) => local.iter().all(|index| disabled.contains(index)),
+			Err(_) => false,
+		}
+	}
+}
+
+impl<PF> Environment<Block> for SkipWhenDisabled<PF>
+where
+	PF: Environment<Block>,
+	PF::Error: Send,
+{
+	type Proposer = PF::Proposer;
+	type CreateProposer =
+		Either<PF::CreateProposer, future::Ready<Result<PF::Proposer, PF::Error>>>;
+	type Error = PF::Error;
+
+	fn init(&mut self, parent_header: &<Block as BlockT>::Header) -> Self::CreateProposer {
+		if self.local_authorities_disabled(parent_header.hash()) {
+			let error = ConsensusError::Other(
+				"the local Aura keys are disabled for the rest of the session".into(),
+			);
+			return Either::Right(future::ready(Err(error.into())))
+		}
+		Either::Left(self.inner.init(parent_header))
+	}
+}
@@ -124,6 +124,8 @@ pub fn create_benchmark_extrinsic(
 		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+		pallet_template::ValidateTemplateCall::<runtime::Runtime>::new(),
+		pallet_template::ValidateTemplateCall::<runtime::Runtime, runtime::Instance1>::new(),
 	);
 
 	let raw_payload = runtime::SignedPayload::from_raw(
@@ -138,6 +140,8 @@ pub fn create_benchmark_extrinsic(
 			(),
 			(),
 			(),
+			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -1,6 +1,7 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	opaque::SessionKeys, AccountId, AuraConfig, BalancesConfig, CouncilMembershipConfig,
+	GenesisConfig, GrandpaConfig, SessionConfig, Signature, SudoConfig, SystemConfig,
+	TemplateModuleConfig, ValidatorSetConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -31,9 +32,17 @@ where
 	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
-/// Generate an Aura authority key.
-pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
-	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
+/// Generate a validator account with its Aura and GRANDPA authority keys.
+pub fn authority_keys_from_seed(s: &str) -> (AccountId, AuraId, GrandpaId) {
+	(
+		get_account_id_from_seed::<sr25519::Public>(s),
+		get_from_seed::<AuraId>(s),
+		get_from_seed::<GrandpaId>(s),
+	)
+}
+
+fn session_keys(aura: AuraId, grandpa: GrandpaId) -> SessionKeys {
+	SessionKeys { aura, grandpa }
 }
 
 pub fn development_config() -> Result<ChainSpec, String> {
@@ -51,7 +60,9 @@ pub fn development_config() -> Result<ChainSpec, String> {
 				// Initial PoA authorities
 				vec![authority_keys_from_seed("Alice")],
 				// Sudo account
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+				// Council members
+				vec![get_account_id_from_seed::<sr25519::Public>("Alice")],
 				// Pre-funded accounts
 				vec![
 					get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -59,6 +70,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Initial pallet-template values
+				vec![
+					(get_account_id_from_seed::<sr25519::Public>("Alice"), 0),
+					(get_account_id_from_seed::<sr25519::Public>("Bob"), 0),
+				],
 				true,
 			)
 		},
@@ -91,7 +107,13 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 				// Initial PoA authorities
 				vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
 				// Sudo account
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				],
 				// Pre-funded accounts
 				vec![
 					get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -107,6 +129,71 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Initial pallet-template values
+				vec![
+					(get_account_id_from_seed::<sr25519::Public>("Alice"), 0),
+					(get_account_id_from_seed::<sr25519::Public>("Bob"), 0),
+				],
+				true,
+			)
+		},
+		// Bootnodes
+		vec![],
+		// Telemetry
+		None,
+		// Protocol ID
+		None,
+		// Properties
+		None,
+		None,
+		// Extensions
+		None,
+	))
+}
+
+/// A local testnet governed by its council and referenda from genesis, without a sudo key.
+pub fn local_governance_config() -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+	Ok(ChainSpec::from_genesis(
+		// Name
+		"Local Governance Testnet",
+		// ID
+		"local_governance",
+		ChainType::Local,
+		move || {
+			testnet_genesis(
+				wasm_binary,
+				// Initial PoA authorities
+				vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
+				// No sudo account
+				None,
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				],
+				// Pre-funded accounts
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+					get_account_id_from_seed::<sr25519::Public>("Dave"),
+					get_account_id_from_seed::<sr25519::Public>("Eve"),
+					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+				],
+				// Initial pallet-template values
+				vec![
+					(get_account_id_from_seed::<sr25519::Public>("Alice"), 0),
+					(get_account_id_from_seed::<sr25519::Public>("Bob"), 0),
+				],
 				true,
 			)
 		},
@@ -127,9 +214,11 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	wasm_binary: &[u8],
-	initial_authorities: Vec<(AuraId, GrandpaId)>,
-	root_key: AccountId,
+	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
+	root_key: Option<AccountId>,
+	council_members: Vec<AccountId>,
 	endowed_accounts: Vec<AccountId>,
+	initial_something: Vec<(AccountId, u32)>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -141,16 +230,36 @@ fn testnet_genesis(
 			// Configure endowed accounts with initial balance of 1 << 60.
 			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
 		},
-		aura: AuraConfig {
-			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect(),
+		validator_set: ValidatorSetConfig {
+			initial_validators: initial_authorities.iter().map(|x| x.0.clone()).collect(),
 		},
-		grandpa: GrandpaConfig {
-			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect(),
+		session: SessionConfig {
+			keys: initial_authorities
+				.iter()
+				.map(|x| (x.0.clone(), x.0.clone(), session_keys(x.1.clone(), x.2.clone())))
+				.collect(),
 		},
+		// The authorities are set by the session pallet.
+		aura: AuraConfig { authorities: vec![] },
+		grandpa: GrandpaConfig { authorities: vec![] },
 		sudo: SudoConfig {
-			// Assign network admin rights.
-			key: Some(root_key),
+			// Assign network admin rights, if any.
+			key: root_key,
+		},
+		council: Default::default(),
+		council_membership: CouncilMembershipConfig {
+			// Also initializes the council's members.
+			members: council_members.try_into().expect("too many council members"),
+			phantom: Default::default(),
 		},
+		democracy: Default::default(),
 		transaction_payment: Default::default(),
+		template_module: TemplateModuleConfig {
+			something: initial_something,
+			// The validators' `tmpl` keys sign the offchain worker's unsigned submissions.
+			authorities: initial_authorities.iter().map(|x| x.0.clone()).collect(),
+			phantom: Default::default(),
+		},
+		second_template_module: Default::default(),
 	}
 }
@@ -1,3 +1,4 @@
+pub mod authorship;
 pub mod chain_spec;
 pub mod rpc;
 pub mod service;
@@ -4,6 +4,7 @@
 mod chain_spec;
 #[macro_use]
 mod service;
+mod authorship;
 mod benchmarking;
 mod cli;
 mod command;
@@ -1,13 +1,16 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
-use node_template_runtime::{self, opaque::Block, RuntimeApi};
+use node_template_runtime::{self, opaque::Block, pallet_template, RuntimeApi};
 use sc_client_api::BlockBackend;
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
 pub use sc_executor::NativeElseWasmExecutor;
-use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpSyncParams};
+use sc_service::{
+	error::Error as ServiceError, ChainType, Configuration, TaskManager, WarpSyncParams,
+};
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_keystore::Keystore;
 use std::{sync::Arc, time::Duration};
 
 // Our native executor instance.
@@ -177,6 +180,14 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		})?;
 
 	if config.offchain_worker.enabled {
+		// Give the template pallet's offchain worker a key to sign its submissions with.
+		if config.chain_spec.chain_type() == ChainType::Development {
+			keystore_container
+				.keystore()
+				.sr25519_generate_new(pallet_template::KEY_TYPE, Some("//Alice"))
+				.expect("Creating key with account Alice should succeed.");
+		}
+
 		sc_service::build_offchain_workers(
 			&config,
 			task_manager.spawn_handle(),
@@ -194,11 +205,17 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 
 	let rpc_extensions_builder = {
 		let client = client.clone();
+		let backend = backend.clone();
 		let pool = transaction_pool.clone();
 
-		Box::new(move |deny_unsafe, _| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+		Box::new(move |deny_unsafe, subscription_executor| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				backend: backend.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				subscription_executor,
+			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
@@ -226,6 +243,12 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			prometheus_registry.as_ref(),
 			telemetry.as_ref().map(|x| x.handle()),
 		);
+		// Do not propose in the slots of this node while the runtime has disabled its key.
+		let proposer_factory = crate::authorship::SkipWhenDisabled::new(
+			proposer_factory,
+			client.clone(),
+			keystore_container.keystore(),
+		);
 
 		let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
 
@@ -0,0 +1,54 @@
+//! Benchmarking setup for pallet-safe-mode
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as SafeMode;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn enter() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[block]
+		{
+			SafeMode::<T>::enter(origin, T::MaxDuration::get())?;
+		}
+
+		assert!(SafeMode::<T>::is_entered());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn exit() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		EnteredUntil::<T>::put(T::MaxDuration::get());
+		#[block]
+		{
+			SafeMode::<T>::exit(origin)?;
+		}
+
+		assert!(!SafeMode::<T>::is_entered());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn auto_exit() {
+		let now = frame_system::Pallet::<T>::block_number();
+		EnteredUntil::<T>::put(now);
+		#[block]
+		{
+			SafeMode::<T>::on_initialize(now);
+		}
+
+		assert!(!SafeMode::<T>::is_entered());
+	}
+
+	impl_benchmark_test_suite!(SafeMode, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,151 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A safe mode that restricts the calls users can make during an incident.
+//!
+//! `ForceOrigin` enters safe mode for at most `MaxDuration` blocks. While it is active the pallet,
+//! used as the runtime's `BaseCallFilter`, only lets `WhitelistedCalls` through. Safe mode is
+//! exited by `ForceOrigin` or automatically when its duration is over. Calls dispatched by Root,
+//! such as those wrapped in `sudo`, are never filtered.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::traits::Contains;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Type representing the weight of this pallet.
+		type WeightInfo: WeightInfo;
+		/// The origin allowed to enter and exit safe mode.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The calls that can still be dispatched while safe mode is active.
+		type WhitelistedCalls: Contains<Self::RuntimeCall>;
+		/// The maximum number of blocks safe mode can be entered for.
+		#[pallet::constant]
+		type MaxDuration: Get<Self::BlockNumber>;
+	}
+
+	/// The block at which safe mode is exited, if it is active.
+	#[pallet::storage]
+	#[pallet::getter(fn entered_until)]
+	pub type EnteredUntil<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// Why safe mode was exited.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ExitReason {
+		/// The duration safe mode was entered for is over.
+		Timeout,
+		/// `ForceOrigin` exited safe mode.
+		Force,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Safe mode was entered until block `until`. [until]
+		Entered { until: T::BlockNumber },
+		/// Safe mode was exited. [reason]
+		Exited { reason: ExitReason },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Safe mode is already active.
+		AlreadyEntered,
+		/// Safe mode is not active.
+		NotEntered,
+		/// The duration is zero or longer than `MaxDuration`.
+		InvalidDuration,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Exit safe mode once its duration is over.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			match <EnteredUntil<T>>::get() {
+				Some(until) if until <= now => {
+					Self::do_exit(ExitReason::Timeout);
+					T::WeightInfo::auto_exit()
+				},
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enter safe mode for `duration` blocks.
+		///
+		/// The origin must be `ForceOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::enter())]
+		pub fn enter(origin: OriginFor<T>, duration: T::BlockNumber) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			ensure!(!<EnteredUntil<T>>::exists(), Error::<T>::AlreadyEntered);
+
+			let until = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			<EnteredUntil<T>>::put(until);
+
+			Self::deposit_event(Event::Entered { until });
+			Ok(())
+		}
+
+		/// Exit safe mode before its duration is over.
+		///
+		/// The origin must be `ForceOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::exit())]
+		pub fn exit(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(<EnteredUntil<T>>::exists(), Error::<T>::NotEntered);
+
+			Self::do_exit(ExitReason::Force);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether safe mode is active.
+		pub fn is_entered() -> bool {
+			<EnteredUntil<T>>::exists()
+		}
+
+		fn do_exit(reason: ExitReason) {
+			<EnteredUntil<T>>::kill();
+			Self::deposit_event(Event::Exited { reason });
+		}
+	}
+}
+
+/// Lets every call through while safe mode is inactive, and only `WhitelistedCalls` while it is
+/// active.
+impl<T: Config> Contains<T::RuntimeCall> for Pallet<T> {
+	fn contains(call: &T::RuntimeCall) -> bool {
+		!Self::is_entered() || T::WhitelistedCalls::contains(call)
+	}
+}
@@ -0,0 +1,74 @@
+use crate::{mock::*, EnteredUntil, Error, Event, ExitReason};
+use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: Vec::new() })
+}
+
+#[test]
+fn entering_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SafeMode::enter(RuntimeOrigin::signed(1), 5), BadOrigin);
+		assert_noop!(SafeMode::enter(RuntimeOrigin::root(), 0), Error::<Test>::InvalidDuration);
+		assert_noop!(SafeMode::enter(RuntimeOrigin::root(), 11), Error::<Test>::InvalidDuration);
+
+		assert_ok!(SafeMode::enter(RuntimeOrigin::root(), 10));
+		System::assert_last_event(Event::Entered { until: 11 }.into());
+		assert_eq!(EnteredUntil::<Test>::get(), Some(11));
+		assert_noop!(SafeMode::enter(RuntimeOrigin::root(), 5), Error::<Test>::AlreadyEntered);
+	});
+}
+
+#[test]
+fn only_whitelisted_calls_pass_in_safe_mode() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(remark().dispatch(RuntimeOrigin::signed(1)));
+
+		assert_ok!(SafeMode::enter(RuntimeOrigin::root(), 5));
+		assert_noop!(
+			remark().dispatch(RuntimeOrigin::signed(1)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+		// Root is not filtered.
+		assert_ok!(remark().dispatch(RuntimeOrigin::root()));
+		// Whitelisted calls pass the filter and are checked as usual.
+		let exit = RuntimeCall::SafeMode(crate::Call::exit {});
+		assert_noop!(exit.clone().dispatch(RuntimeOrigin::signed(1)), BadOrigin);
+		assert_ok!(exit.dispatch(RuntimeOrigin::root()));
+
+		assert_ok!(remark().dispatch(RuntimeOrigin::signed(1)));
+	});
+}
+
+#[test]
+fn safe_mode_can_be_exited_early() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SafeMode::exit(RuntimeOrigin::root()), Error::<Test>::NotEntered);
+
+		assert_ok!(SafeMode::enter(RuntimeOrigin::root(), 5));
+		assert_noop!(SafeMode::exit(RuntimeOrigin::signed(1)), BadOrigin);
+		assert_ok!(SafeMode::exit(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Exited { reason: ExitReason::Force }.into());
+		assert!(!SafeMode::is_entered());
+	});
+}
+
+#[test]
+fn safe_mode_exits_when_its_duration_is_over() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(RuntimeOrigin::root(), 5));
+
+		for block in 2..6 {
+			System::set_block_number(block);
+			SafeMode::on_initialize(block);
+			assert!(SafeMode::is_entered());
+		}
+
+		System::set_block_number(6);
+		SafeMode::on_initialize(6);
+		System::assert_last_event(Event::Exited { reason: ExitReason::Timeout }.into());
+		assert!(!SafeMode::is_entered());
+		assert_ok!(remark().dispatch(RuntimeOrigin::signed(1)));
+	});
+}
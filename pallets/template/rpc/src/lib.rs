@@ -0,0 +1,299 @@
+//! RPC interface for pallet-template.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Decode, Encode};
+use futures::{future, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use sc_client_api::{Backend, BlockchainEvents, StateBackend};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	hashing::{blake2_128, twox_128},
+	traits::SpawnNamed,
+};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
+
+pub use pallet_template_runtime_api::TemplateApi as TemplateRuntimeApi;
+
+/// RPC methods for reading pallet-template state.
+///
+/// Every method takes an optional `instance`, the number the runtime gives the pallet instance to
+/// read. It defaults to `0`, the instance read by runtimes older than version 3 of the runtime
+/// API, which do not support any other.
+#[rpc(client, server)]
+pub trait TemplateApi<BlockHash, AccountId, BlockNumber> {
+	/// The value stored by `who` at the best block, if any.
+	#[method(name = "template_getValue")]
+	fn get_value(&self, who: AccountId, instance: Option<u8>) -> RpcResult<Option<u32>>;
+
+	/// The value stored by `who` at block `at`, if any.
+	#[method(name = "template_getValueAt")]
+	fn get_value_at(
+		&self,
+		who: AccountId,
+		at: BlockHash,
+		instance: Option<u8>,
+	) -> RpcResult<Option<u32>>;
+
+	/// The most recent writes of values at the best block as `(block number, account, value)`,
+	/// oldest first.
+	#[method(name = "template_getHistory")]
+	fn get_history(&self, instance: Option<u8>) -> RpcResult<Vec<(BlockNumber, AccountId, u32)>>;
+
+	/// The most recent writes of values at block `at` as `(block number, account, value)`, oldest
+	/// first.
+	#[method(name = "template_getHistoryAt")]
+	fn get_history_at(
+		&self,
+		at: BlockHash,
+		instance: Option<u8>,
+	) -> RpcResult<Vec<(BlockNumber, AccountId, u32)>>;
+}
+
+/// RPC subscriptions to pallet-template events.
+#[rpc(client, server)]
+pub trait TemplateEventsApi<BlockHash, AccountId> {
+	/// Watch the events of pallet-template concerning `who`.
+	///
+	/// Yields `(block hash, event indices)` for every new best block with such events, where the
+	/// indices are into the block's `System::Events`. Events of all instances of the pallet are
+	/// included.
+	#[subscription(
+		name = "template_subscribeAccountEvents" => "template_accountEvents",
+		unsubscribe = "template_unsubscribeAccountEvents",
+		item = (BlockHash, Vec<u32>)
+	)]
+	fn subscribe_account_events(&self, who: AccountId);
+}
+
+/// Provides RPC methods to query pallet-template state.
+pub struct Template<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> Template<C, P> {
+	/// Creates a new instance of the Template RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The runtime does not support the call.
+	Unsupported,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::Unsupported => 2,
+		}
+	}
+}
+
+impl<C, Block, AccountId, BlockNumber>
+	TemplateApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Template<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TemplateRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec,
+	BlockNumber: Codec,
+{
+	fn get_value(&self, who: AccountId, instance: Option<u8>) -> RpcResult<Option<u32>> {
+		self.get_value_at(who, self.client.info().best_hash, instance)
+	}
+
+	fn get_value_at(
+		&self,
+		who: AccountId,
+		at: Block::Hash,
+		instance: Option<u8>,
+	) -> RpcResult<Option<u32>> {
+		let api = self.client.runtime_api();
+		let runtime_error = |e: String| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query value.",
+				Some(e),
+			))
+		};
+
+		match instance.unwrap_or_default() {
+			0 => api.get_value(at, who),
+			instance => {
+				self.ensure_api_version(at, 3, "The runtime only exposes the first instance.")?;
+				api.get_instance_value(at, instance, who)
+			},
+		}
+		.map_err(|e| runtime_error(e.to_string()).into())
+	}
+
+	fn get_history(&self, instance: Option<u8>) -> RpcResult<Vec<(BlockNumber, AccountId, u32)>> {
+		self.get_history_at(self.client.info().best_hash, instance)
+	}
+
+	fn get_history_at(
+		&self,
+		at: Block::Hash,
+		instance: Option<u8>,
+	) -> RpcResult<Vec<(BlockNumber, AccountId, u32)>> {
+		let api = self.client.runtime_api();
+		let runtime_error = |e: String| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query history.",
+				Some(e),
+			))
+		};
+
+		self.ensure_api_version(at, 2, "The runtime does not keep a history.")?;
+		match instance.unwrap_or_default() {
+			0 => api.history(at),
+			instance => {
+				self.ensure_api_version(at, 3, "The runtime only exposes the first instance.")?;
+				api.instance_history(at, instance)
+			},
+		}
+		.map_err(|e| runtime_error(e.to_string()).into())
+	}
+}
+
+impl<C, Block> Template<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+{
+	/// Fails with [`Error::Unsupported`] unless the runtime at `at` implements at least `version`
+	/// of the runtime API.
+	fn ensure_api_version<AccountId, BlockNumber>(
+		&self,
+		at: Block::Hash,
+		version: u32,
+		unsupported: &'static str,
+	) -> RpcResult<()>
+	where
+		C::Api: TemplateRuntimeApi<Block, AccountId, BlockNumber>,
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		let actual = self
+			.client
+			.runtime_api()
+			.api_version::<dyn TemplateRuntimeApi<Block, AccountId, BlockNumber>>(at)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query the runtime API version.",
+					Some(e.to_string()),
+				))
+			})?;
+		if actual.unwrap_or_default() < version {
+			return Err(CallError::Custom(ErrorObject::owned(
+				Error::Unsupported.into(),
+				unsupported,
+				None::<()>,
+			))
+			.into())
+		}
+		Ok(())
+	}
+}
+
+/// Provides RPC subscriptions to pallet-template events.
+///
+/// Events are found through the topics they are indexed by in `System::EventTopics`, so only the
+/// storage of the topic being watched is read for each block.
+pub struct TemplateEvents<C, BE, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Shared reference to the backend, to read the topics from.
+	backend: Arc<BE>,
+	/// Executor to drive the subscriptions.
+	executor: Arc<dyn SpawnNamed>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, BE, Block> TemplateEvents<C, BE, Block> {
+	/// Creates a new instance of the TemplateEvents RPC helper.
+	pub fn new(client: Arc<C>, backend: Arc<BE>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self { client, backend, executor, _marker: Default::default() }
+	}
+}
+
+impl<C, BE, Block, AccountId> TemplateEventsApiServer<<Block as BlockT>::Hash, AccountId>
+	for TemplateEvents<C, BE, Block>
+where
+	Block: BlockT,
+	C: BlockchainEvents<Block> + Send + Sync + 'static,
+	BE: Backend<Block> + Send + Sync + 'static,
+	AccountId: Encode,
+{
+	fn subscribe_account_events(
+		&self,
+		sink: SubscriptionSink,
+		who: AccountId,
+	) -> SubscriptionResult {
+		let topic =
+			pallet_template::topics::account::<<Block::Header as HeaderT>::Hashing, _>(&who);
+		let key = event_topics_key(topic);
+		let backend = self.backend.clone();
+
+		let stream = self.client.import_notification_stream().filter_map(move |notification| {
+			let indices = notification
+				.is_new_best
+				.then(|| indexed_events::<Block, BE>(&backend, &notification.header, &key))
+				.flatten();
+			future::ready(indices.map(|indices| (notification.hash, indices)))
+		});
+
+		let fut = async move {
+			sink.pipe_from_stream(stream).await;
+		};
+		self.executor.spawn("template-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
+
+/// The storage key of `topic` in `System::EventTopics`.
+fn event_topics_key(topic: impl Encode) -> Vec<u8> {
+	let topic = topic.encode();
+	let mut key = twox_128(b"System").to_vec();
+	key.extend(twox_128(b"EventTopics"));
+	key.extend(blake2_128(&topic));
+	key.extend(topic);
+	key
+}
+
+/// The indices of the events deposited in the block of `header` that are indexed under the topic
+/// whose storage key is `key`, or `None` if there are none or they could not be read.
+fn indexed_events<Block: BlockT, BE: Backend<Block>>(
+	backend: &BE,
+	header: &Block::Header,
+	key: &[u8],
+) -> Option<Vec<u32>> {
+	let encoded = backend.state_at(header.hash()).ok()?.storage(key).ok()??;
+	// The topics are reset at the start of every block, but only trust entries of this block.
+	let indices: Vec<u32> = Vec::<(NumberFor<Block>, u32)>::decode(&mut &encoded[..])
+		.ok()?
+		.into_iter()
+		.filter(|(number, _)| number == header.number())
+		.map(|(_, index)| index)
+		.collect();
+	(!indices.is_empty()).then_some(indices)
+}
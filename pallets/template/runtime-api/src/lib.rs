@@ -0,0 +1,37 @@
+//! Runtime API definition for pallet-template.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Read the state of pallet-template without computing storage keys by hand.
+	///
+	/// Version 2 added `history`. Version 3 added `get_instance_value` and `instance_history`.
+	#[api_version(3)]
+	pub trait TemplateApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The value stored by `who`, if any.
+		fn get_value(who: AccountId) -> Option<u32>;
+
+		/// The most recent writes of values as `(block number, account, value)`, oldest first.
+		#[api_version(2)]
+		fn history() -> Vec<(BlockNumber, AccountId, u32)>;
+
+		/// Like `get_value`, for the pallet instance numbered `instance` by the runtime.
+		///
+		/// Instance `0` is the one `get_value` reads. Unknown instances return `None`.
+		#[api_version(3)]
+		fn get_instance_value(instance: u8, who: AccountId) -> Option<u32>;
+
+		/// Like `history`, for the pallet instance numbered `instance` by the runtime.
+		///
+		/// Instance `0` is the one `history` reads. Unknown instances return an empty list.
+		#[api_version(3)]
+		fn instance_history(instance: u8) -> Vec<(BlockNumber, AccountId, u32)>;
+	}
+}
@@ -5,31 +5,540 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{Currency, EnsureOrigin, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{
+	offchain::{AppCrypto, SignedPayload},
+	RawOrigin,
+};
+use sp_runtime::{
+	traits::{Bounded, IdentifyAccount},
+	RuntimeAppPublic,
+};
+use sp_std::{vec, vec::Vec};
 
-#[benchmarks]
+/// Give `who` enough free balance to reserve deposits.
+fn fund<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T, I>::max_value() / 2u32.into());
+}
+
+/// Store a value for `who`, reserving its deposit.
+fn store_with_deposit<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	fund::<T, I>(who);
+	Template::<T, I>::do_something(RawOrigin::Signed(who.clone()).into(), 100u32)
+		.expect("the account is funded; qed");
+}
+
+/// Commit for `who` as `commit` would, reserving the deposit.
+fn insert_commit<T: Config<I>, I: 'static>(who: &T::AccountId, hash: H256) {
+	fund::<T, I>(who);
+	let deposit = T::CommitDeposit::get();
+	T::Currency::reserve(who, deposit).expect("the account is funded; qed");
+	Commits::<T, I>::insert(who, (hash, deposit));
+	CommitCount::<T, I>::mutate(|count| *count += 1);
+}
+
+/// Make `n` accounts feeders, returning them.
+fn fill_feeders<T: Config<I>, I: 'static>(n: u32) -> Vec<T::AccountId> {
+	let feeders: Vec<T::AccountId> = (0..n).map(|i| account("feeder", i, 0)).collect();
+	Feeders::<T, I>::put(BoundedVec::try_from(feeders.clone()).expect("within the bound; qed"));
+	feeders
+}
+
+/// Make `n` accounts authorities, returning them.
+fn fill_authorities<T: Config<I>, I: 'static>(n: u32) -> Vec<T::AccountId> {
+	let authorities: Vec<T::AccountId> = (0..n).map(|i| account("authority", i, 0)).collect();
+	Authorities::<T, I>::put(
+		BoundedVec::try_from(authorities.clone()).expect("within the bound; qed"),
+	);
+	authorities
+}
+
+/// Schedule `n` updates from funded accounts for block `at`.
+fn fill_schedule<T: Config<I>, I: 'static>(at: T::BlockNumber, n: u32) {
+	let scheduled: Vec<_> = (0..n)
+		.map(|i| {
+			let who: T::AccountId = account("scheduler", i, 0);
+			fund::<T, I>(&who);
+			(who, i)
+		})
+		.collect();
+	Scheduled::<T, I>::insert(at, BoundedVec::try_from(scheduled).expect("within the bound; qed"));
+}
+
+#[instance_benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
+	fn do_something_insert() {
 		let value = 100u32.into();
 		let caller: T::AccountId = whitelisted_caller();
+		fund::<T, I>(&caller);
+		#[extrinsic_call]
+		do_something(RawOrigin::Signed(caller.clone()), value);
+
+		assert_eq!(Something::<T, I>::get(&caller), Some(value));
+	}
+
+	#[benchmark]
+	fn do_something_update() {
+		let caller: T::AccountId = whitelisted_caller();
+		store_with_deposit::<T, I>(&caller);
+		// Update in a later block so the value's expiry is rescheduled.
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		do_something(RawOrigin::Signed(caller.clone()), 200u32);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Something::<T, I>::get(&caller), Some(200u32));
 	}
 
 	#[benchmark]
 	fn cause_error() {
-		Something::<T>::put(100u32);
 		let caller: T::AccountId = whitelisted_caller();
+		Something::<T, I>::insert(&caller, 100u32);
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		cause_error(RawOrigin::Signed(caller.clone()));
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(Something::<T, I>::get(&caller), Some(101u32));
+	}
+
+	#[benchmark]
+	fn cause_error_failed() {
+		let caller: T::AccountId = whitelisted_caller();
+		#[block]
+		{
+			assert!(
+				Template::<T, I>::cause_error(RawOrigin::Signed(caller.clone()).into()).is_err()
+			);
+		}
+
+		assert_eq!(Something::<T, I>::get(&caller), None);
+	}
+
+	#[benchmark]
+	fn increment_by() {
+		let caller: T::AccountId = whitelisted_caller();
+		Something::<T, I>::insert(&caller, 100u32);
+		let by = T::MaxIncrementStep::get();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), by);
+
+		assert!(Something::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn clear_something() {
+		let caller: T::AccountId = whitelisted_caller();
+		store_with_deposit::<T, I>(&caller);
+		#[extrinsic_call]
+		clear_something(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Something::<T, I>::get(&caller), None);
+		assert_eq!(Deposits::<T, I>::get(&caller), None);
+	}
+
+	#[benchmark]
+	fn submit_something_unsigned() {
+		let key =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+				None,
+			);
+		let generic_public: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+			key.into();
+		let public: T::Public = generic_public.into();
+		// The signer is checked against a full list of authorities.
+		let mut authorities = fill_authorities::<T, I>(T::MaxAuthorities::get() - 1);
+		authorities.push(public.clone().into_account());
+		Authorities::<T, I>::put(BoundedVec::try_from(authorities).expect("within the bound; qed"));
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let payload = SomethingPayload { block_number, something: 100u32, public: public.clone() };
+		let signature = SignedPayload::<T>::sign::<T::AuthorityId>(&payload)
+			.expect("the key was just generated; qed");
+		#[extrinsic_call]
+		submit_something_unsigned(RawOrigin::None, payload, signature);
+
+		assert_eq!(Something::<T, I>::get(public.into_account()), Some(100u32));
+	}
+
+	#[benchmark]
+	fn force_set_something() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, 0);
+		#[block]
+		{
+			Template::<T, I>::force_set_something(origin, who.clone(), 100u32)?;
+		}
+
+		assert_eq!(Something::<T, I>::get(&who), Some(100u32));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_clear_something() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, 0);
+		store_with_deposit::<T, I>(&who);
+		#[block]
+		{
+			Template::<T, I>::force_clear_something(origin, who.clone())?;
+		}
+
+		assert_eq!(Something::<T, I>::get(&who), None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_idle_base() {
+		let now = frame_system::Pallet::<T>::block_number().max(1u32.into());
+		NextExpiryBlock::<T, I>::put(now + 1u32.into());
+		#[block]
+		{
+			Template::<T, I>::on_idle(now, Weight::MAX);
+		}
+
+		assert_eq!(NextExpiryBlock::<T, I>::get(), now + 1u32.into());
+	}
+
+	#[benchmark]
+	fn purge_expired(n: Linear<0, 256>) {
+		// Benchmarked up to the runtime's bound, clamped to the configured one.
+		let n = n.min(T::MaxExpiringPerBlock::get());
+		let who: Vec<T::AccountId> = (0..n).map(|i| account("who", i, 0)).collect();
+		for who in &who {
+			store_with_deposit::<T, I>(who);
+		}
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::SomethingTtl::get();
+		#[block]
+		{
+			Template::<T, I>::purge_expired(expires_at);
+		}
+
+		for who in &who {
+			assert_eq!(Something::<T, I>::get(who), None);
+		}
+	}
+
+	#[benchmark]
+	fn schedule_something(s: Linear<0, 63>) {
+		// Benchmarked up to the runtime's bound, clamped to the configured one.
+		let s = s.min(T::MaxScheduledPerBlock::get() - 1);
+		let at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		fill_schedule::<T, I>(at, s);
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 100u32, at);
+
+		assert_eq!(Scheduled::<T, I>::get(at).last(), Some(&(caller, 100u32)));
+	}
+
+	#[benchmark]
+	fn cancel_scheduled(s: Linear<1, 64>) {
+		let s = s.min(T::MaxScheduledPerBlock::get());
+		let at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		fill_schedule::<T, I>(at, s - 1);
+		let caller: T::AccountId = whitelisted_caller();
+		Scheduled::<T, I>::try_append(at, (caller.clone(), 100u32)).expect("within the bound; qed");
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), at);
+
+		assert_eq!(Scheduled::<T, I>::get(at).len() as u32, s - 1);
+	}
+
+	#[benchmark]
+	fn apply_scheduled(s: Linear<0, 64>) {
+		let s = s.min(T::MaxScheduledPerBlock::get());
+		let at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		fill_schedule::<T, I>(at, s);
+		frame_system::Pallet::<T>::set_block_number(at);
+		#[block]
+		{
+			Template::<T, I>::on_initialize(at);
+		}
+
+		assert!(!Scheduled::<T, I>::contains_key(at));
+		assert_eq!(Deposits::<T, I>::iter().count() as u32, s);
+	}
+
+	#[benchmark]
+	fn set_entry(k: Linear<0, 64>, v: Linear<0, 256>) {
+		// Benchmarked up to the runtime's bounds, clamped to the configured ones.
+		let k = k.min(T::MaxKeyLen::get());
+		let v = v.min(T::MaxValueLen::get());
+		let caller: T::AccountId = whitelisted_caller();
+		let key =
+			KeyOf::<T, I>::try_from(vec![0u8; k as usize]).expect("clamped to the bound; qed");
+		let value =
+			ValueOf::<T, I>::try_from(vec![1u8; v as usize]).expect("clamped to the bound; qed");
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), key.clone(), value.clone());
+
+		assert_eq!(Entries::<T, I>::get(&caller, &key), Some(value));
+	}
+
+	#[benchmark]
+	fn remove_entry(k: Linear<0, 64>) {
+		let k = k.min(T::MaxKeyLen::get());
+		let caller: T::AccountId = whitelisted_caller();
+		let key =
+			KeyOf::<T, I>::try_from(vec![0u8; k as usize]).expect("clamped to the bound; qed");
+		let value = ValueOf::<T, I>::default();
+		Template::<T, I>::set_entry(RawOrigin::Signed(caller.clone()).into(), key.clone(), value)
+			.expect("the caller holds no entries; qed");
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), key.clone());
+
+		assert!(!Entries::<T, I>::contains_key(&caller, &key));
+	}
+
+	#[benchmark]
+	fn clear_all(n: Linear<1, 100>) {
+		let n = n.min(T::MaxEntries::get());
+		let caller: T::AccountId = whitelisted_caller();
+		for i in 0..n {
+			let key = KeyOf::<T, I>::try_from(i.to_le_bytes().to_vec())
+				.expect("keys are at least four bytes long; qed");
+			Entries::<T, I>::insert(&caller, key, ValueOf::<T, I>::default());
+		}
+		EntryCount::<T, I>::insert(&caller, n);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Entries::<T, I>::iter_prefix(&caller).count(), 0);
+	}
+
+	#[benchmark]
+	fn commit() {
+		let now = frame_system::Pallet::<T>::block_number();
+		CurrentPhase::<T, I>::put((RoundPhase::Commit, now + 1u32.into()));
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T, I>(&caller);
+		let hash = Template::<T, I>::commitment(&caller, 100u32, &[0u8; 32]);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), hash);
+
+		assert_eq!(Commits::<T, I>::get(&caller).map(|(hash, _)| hash), Some(hash));
+	}
+
+	#[benchmark]
+	fn reveal() {
+		let now = frame_system::Pallet::<T>::block_number();
+		CurrentPhase::<T, I>::put((RoundPhase::Reveal, now + 1u32.into()));
+		let caller: T::AccountId = whitelisted_caller();
+		let salt = [0u8; 32];
+		insert_commit::<T, I>(&caller, Template::<T, I>::commitment(&caller, 100u32, &salt));
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 100u32, salt);
+
+		assert_eq!(Something::<T, I>::get(&caller), Some(100u32));
+	}
+
+	#[benchmark]
+	fn start_reveal_phase() {
+		let now = frame_system::Pallet::<T>::block_number();
+		CurrentPhase::<T, I>::put((RoundPhase::Commit, now));
+		#[block]
+		{
+			Template::<T, I>::advance_phase(now);
+		}
+
+		assert_eq!(CurrentPhase::<T, I>::get().map(|(phase, _)| phase), Some(RoundPhase::Reveal));
+	}
+
+	#[benchmark]
+	fn start_commit_phase(n: Linear<0, 100>) {
+		// Benchmarked up to the runtime's bound, clamped to the configured one.
+		let n = n.min(T::MaxCommits::get());
+		let now = frame_system::Pallet::<T>::block_number();
+		CurrentPhase::<T, I>::put((RoundPhase::Reveal, now));
+		for i in 0..n {
+			insert_commit::<T, I>(&account("committer", i, 0), H256::default());
+		}
+		#[block]
+		{
+			Template::<T, I>::advance_phase(now);
+		}
+
+		assert_eq!(CurrentPhase::<T, I>::get().map(|(phase, _)| phase), Some(RoundPhase::Commit));
+		assert_eq!(CommitCount::<T, I>::get(), 0);
+	}
+
+	#[benchmark]
+	fn submit_value() {
+		let feeders = fill_feeders::<T, I>(T::MaxFeeders::get());
+		let caller = feeders.last().expect("`MaxFeeders` is not zero; qed").clone();
+		let submissions: Vec<_> = feeders
+			.iter()
+			.take(feeders.len() - 1)
+			.map(|feeder| (feeder.clone(), 100u32))
+			.collect();
+		Submissions::<T, I>::put(BoundedVec::try_from(submissions).expect("within the bound; qed"));
+		Mode::<T, I>::put(WriteMode::Oracle);
+		#[block]
+		{
+			Template::<T, I>::do_something(RawOrigin::Signed(caller.clone()).into(), 100u32)
+				.expect("the caller is a feeder; qed");
+		}
+
+		assert_eq!(Submissions::<T, I>::get().len() as u32, T::MaxFeeders::get());
+	}
+
+	#[benchmark]
+	fn finalize_round(s: Linear<1, 32>) {
+		// Benchmarked up to the runtime's bound, clamped to the configured one.
+		let s = s.min(T::MaxFeeders::get());
+		let submissions: Vec<_> = fill_feeders::<T, I>(s)
+			.into_iter()
+			.enumerate()
+			.map(|(i, feeder)| (feeder, 100u32 + i as u32))
+			.collect();
+		Submissions::<T, I>::put(BoundedVec::try_from(submissions).expect("within the bound; qed"));
+		#[block]
+		{
+			Template::<T, I>::finalize_round();
+		}
+
+		assert!(Something::<T, I>::contains_key(Template::<T, I>::oracle_account()));
+	}
+
+	#[benchmark]
+	fn set_mode() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[block]
+		{
+			Template::<T, I>::set_mode(origin, WriteMode::Oracle)?;
+		}
+
+		assert_eq!(Mode::<T, I>::get(), WriteMode::Oracle);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_feeder() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_feeders::<T, I>(T::MaxFeeders::get() - 1);
+		let who: T::AccountId = account("who", 0, 0);
+		#[block]
+		{
+			Template::<T, I>::add_feeder(origin, who.clone())?;
+		}
+
+		assert!(Feeders::<T, I>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_feeder() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let feeders = fill_feeders::<T, I>(T::MaxFeeders::get());
+		let who = feeders.last().expect("`MaxFeeders` is not zero; qed").clone();
+		#[block]
+		{
+			Template::<T, I>::remove_feeder(origin, who.clone())?;
+		}
+
+		assert!(!Feeders::<T, I>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[block]
+		{
+			Template::<T, I>::pause(origin)?;
+		}
+
+		assert!(Paused::<T, I>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unpause() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Paused::<T, I>::put(true);
+		#[block]
+		{
+			Template::<T, I>::unpause(origin)?;
+		}
+
+		assert!(!Paused::<T, I>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_authority() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_authorities::<T, I>(T::MaxAuthorities::get() - 1);
+		let who: T::AccountId = account("who", 0, 0);
+		#[block]
+		{
+			Template::<T, I>::add_authority(origin, who.clone())?;
+		}
+
+		assert!(Authorities::<T, I>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_authority() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let authorities = fill_authorities::<T, I>(T::MaxAuthorities::get());
+		let who = authorities.last().expect("`MaxAuthorities` is not zero; qed").clone();
+		#[block]
+		{
+			Template::<T, I>::remove_authority(origin, who.clone())?;
+		}
+
+		assert!(!Authorities::<T, I>::get().contains(&who));
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{new_test_ext, ExtBuilder, Policy, Test};
+	use frame_benchmarking::Benchmarking;
+	use frame_support::instances::Instance1;
+
+	// The suite above only covers the default instance.
+	#[test]
+	fn benchmarks_run_for_another_instance() {
+		for benchmark in <Template<Test, Instance1> as Benchmarking>::benchmarks(false) {
+			new_test_ext().execute_with(|| {
+				Template::<Test, Instance1>::test_bench_by_name(&benchmark.name)
+					.unwrap_or_else(|e| panic!("{:?}: {:?}", benchmark.name, e));
+			});
+		}
+	}
+
+	// The suite above only covers the default overflow policy.
+	#[test]
+	fn benchmarks_run_for_each_overflow_policy() {
+		for policy in [Policy::Saturating, Policy::Wrapping] {
+			for benchmark in <Template<Test> as Benchmarking>::benchmarks(false) {
+				ExtBuilder::default().with_overflow_policy(policy).build().execute_with(|| {
+					Template::<Test>::test_bench_by_name(&benchmark.name).unwrap_or_else(|e| {
+						panic!("{:?} with {:?}: {:?}", benchmark.name, policy, e)
+					});
+				});
+			}
+		}
+	}
+}
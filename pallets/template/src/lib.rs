@@ -13,96 +13,1511 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
+mod offchain;
+pub mod overflow;
+pub mod topics;
+mod validation;
 pub mod weights;
+pub use offchain::DEFAULT_URL;
+pub use validation::{ValidateTemplateCall, PAUSED, RATE_LIMITED};
 pub use weights::*;
 
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use scale_info::TypeInfo;
+use sp_core::{crypto::KeyTypeId, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	RuntimeDebug,
+};
+
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::template";
+
+/// The key type of the keys the offchain worker signs its submissions with.
+///
+/// Keys of this type can be added to a node's keystore with the `author_insertKey` RPC.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"tmpl");
+
+/// The crypto used by the offchain worker to sign transactions and payloads.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// The identifier of the offchain worker's signing keys, used as `Config::AuthorityId`.
+	pub struct TemplateAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for TemplateAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	// Used by runtimes, such as the mock, that sign with plain sr25519 keys.
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for TemplateAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// A value fetched by the offchain worker, submitted in an unsigned transaction and signed by one
+/// of the worker's local keys.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SomethingPayload<Public, BlockNumber> {
+	/// The block at which the offchain worker fetched the value.
+	pub block_number: BlockNumber,
+	/// The fetched value.
+	pub something: u32,
+	/// The key that signed this payload. The value is stored for its account.
+	pub public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for SomethingPayload<T::Public, T::BlockNumber> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+/// A write of a value recorded in the history.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct HistoryItem<AccountId, BlockNumber> {
+	/// The block the value was written in.
+	pub block_number: BlockNumber,
+	/// The account the value was written for.
+	pub who: AccountId,
+	/// The written value.
+	pub something: u32,
+}
+
+/// How `do_something` writes values.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum WriteMode {
+	/// Each account stores its own value.
+	#[default]
+	Direct,
+	/// Feeders submit values, and their median is stored for the oracle account at the end of
+	/// the block.
+	Oracle,
+}
+
+/// The phases of a commit-reveal round.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RoundPhase {
+	/// Accounts commit to the hash of a value.
+	Commit,
+	/// Accounts reveal the values they committed to.
+	Reveal,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use crate::overflow::OverflowPolicy;
+	use frame_support::{
+		dispatch::{Pays, PostDispatchInfo, WithPostDispatchInfo},
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{Currency, OnUnbalanced, PalletInfoAccess, ReservableCurrency},
+		weights::WeightMeter,
+		PalletId,
+	};
+	use frame_system::{
+		offchain::{AppCrypto, CreateSignedTransaction},
+		pallet_prelude::*,
+	};
+	use sp_runtime::{
+		traits::{AccountIdConversion, IdentifyAccount, One, Saturating, Zero},
+		Permill, SaturatedConversion,
+	};
+	use sp_std::vec::Vec;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>:
+		CreateSignedTransaction<Call<Self, I>> + frame_system::Config
+	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
+		/// The identifier type for the keys the offchain worker signs with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// The maximum number of accounts whose keys can sign unsigned submissions.
+		#[pallet::constant]
+		type MaxAuthorities: Get<u32>;
+		/// Number of blocks between two runs of the offchain worker. This is also the minimum
+		/// number of blocks between two accepted unsigned submissions. Must not be zero.
+		#[pallet::constant]
+		type OffchainInterval: Get<Self::BlockNumber>;
+		/// The priority of unsigned transactions submitted by the offchain worker.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// The origin allowed to set or clear the value stored for any account.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The currency deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The deposit reserved from an account when it stores a value, released when the value is
+		/// cleared.
+		#[pallet::constant]
+		type SomethingDeposit: Get<BalanceOf<Self, I>>;
+		/// Number of blocks after which a stored value expires and is purged in `on_idle`. Zero
+		/// disables expiry.
+		#[pallet::constant]
+		type SomethingTtl: Get<Self::BlockNumber>;
+		/// The maximum number of values that can expire at the same block. Values past it expire
+		/// at the next block instead.
+		#[pallet::constant]
+		type MaxExpiringPerBlock: Get<u32>;
+		/// The maximum number of value updates that can be scheduled for the same block.
+		#[pallet::constant]
+		type MaxScheduledPerBlock: Get<u32>;
+		/// How incrementing a value past `u32::MAX` is handled.
+		type OverflowPolicy: OverflowPolicy;
+		/// The largest step a value can be incremented by in one call.
+		#[pallet::constant]
+		type MaxIncrementStep: Get<u32>;
+		/// Number of blocks in a rate-limiting period. Zero disables rate limiting.
+		#[pallet::constant]
+		type Period: Get<Self::BlockNumber>;
+		/// The maximum number of calls writing a value an account can make in one period.
+		#[pallet::constant]
+		type MaxWritesPerPeriod: Get<u32>;
+		/// The maximum length of a registry key.
+		#[pallet::constant]
+		type MaxKeyLen: Get<u32>;
+		/// The maximum length of a registry value.
+		#[pallet::constant]
+		type MaxValueLen: Get<u32>;
+		/// The maximum number of registry entries an account can hold.
+		#[pallet::constant]
+		type MaxEntries: Get<u32>;
+		/// Number of blocks in the commit phase of a commit-reveal round. Zero disables
+		/// commit-reveal.
+		#[pallet::constant]
+		type CommitPhaseLength: Get<Self::BlockNumber>;
+		/// Number of blocks in the reveal phase of a commit-reveal round. Must not be zero.
+		#[pallet::constant]
+		type RevealPhaseLength: Get<Self::BlockNumber>;
+		/// The deposit reserved for a commitment, slashed if it is not revealed in time.
+		#[pallet::constant]
+		type CommitDeposit: Get<BalanceOf<Self, I>>;
+		/// The maximum number of commitments in a round.
+		#[pallet::constant]
+		type MaxCommits: Get<u32>;
+		/// Handler for the deposits slashed for unrevealed commitments.
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+		/// The pallet's id, used to derive the account the oracle's value is stored for.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The maximum number of oracle feeders.
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+		/// How far a submission can be from the median of a round's submissions before it is
+		/// discarded as an outlier.
+		#[pallet::constant]
+		type MaxDeviation: Get<Permill>;
+		/// The number of most recent writes kept in the history. Zero disables the history.
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
 	}
 
+	pub(crate) type BalanceOf<T, I = ()> =
+		<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type NegativeImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// A key of the registry.
+	pub type KeyOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxKeyLen>;
+	/// A value of the registry.
+	pub type ValueOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxValueLen>;
+
 	// The pallet's runtime storage items.
 	// https://docs.substrate.io/main-docs/build/runtime-storage/
 	#[pallet::storage]
 	#[pallet::getter(fn something)]
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+	/// The value stored by each account.
+	pub type Something<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	/// The deposit reserved from each account for its stored value.
+	///
+	/// Values set by `AdminOrigin`, the offchain worker or at genesis hold no deposit.
+	#[pallet::storage]
+	pub type Deposits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>>;
+
+	/// The block at which the value stored by each account expires.
+	///
+	/// Values stored at genesis or before expiry was introduced never expire.
+	#[pallet::storage]
+	pub type ExpiresAt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// The accounts whose values expire at each block.
+	///
+	/// Entries whose value was rewritten or cleared since they were queued are skipped when
+	/// purging.
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::AccountId, T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose expiry queue `on_idle` will purge.
+	#[pallet::storage]
+	pub type NextExpiryBlock<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The latest block values are queued to expire at. Values stored once its queue is full
+	/// expire at the block after it.
+	#[pallet::storage]
+	pub type LastExpiryBlock<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The first block at which an unsigned submission from the offchain worker is accepted.
+	#[pallet::storage]
+	pub type NextUnsignedAt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The accounts whose keys can sign the payloads of unsigned submissions.
+	#[pallet::storage]
+	pub type Authorities<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxAuthorities>, ValueQuery>;
+
+	/// The value updates scheduled for each block, applied in `on_initialize`.
+	#[pallet::storage]
+	pub type Scheduled<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<(T::AccountId, u32), T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The start of the period each account last wrote a value in, and its number of writes in
+	/// that period.
+	#[pallet::storage]
+	pub type WriteCounts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::BlockNumber, u32), ValueQuery>;
+
+	/// The registry entries of each account.
+	#[pallet::storage]
+	pub type Entries<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		KeyOf<T, I>,
+		ValueOf<T, I>,
+	>;
+
+	/// The number of registry entries held by each account.
+	#[pallet::storage]
+	pub type EntryCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The phase of the current commit-reveal round and the block it ends at.
+	///
+	/// `None` until the first round starts.
+	#[pallet::storage]
+	pub type CurrentPhase<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (RoundPhase, T::BlockNumber)>;
+
+	/// The commitment of each account in the current round and the deposit reserved for it.
+	///
+	/// Commitments are removed when revealed, so those left at the end of a round are slashed.
+	#[pallet::storage]
+	pub type Commits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (H256, BalanceOf<T, I>)>;
+
+	/// The number of entries in `Commits`.
+	#[pallet::storage]
+	pub type CommitCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// How `do_something` writes values.
+	#[pallet::storage]
+	pub type Mode<T: Config<I>, I: 'static = ()> = StorageValue<_, WriteMode, ValueQuery>;
+
+	/// The accounts allowed to submit values in oracle mode.
+	#[pallet::storage]
+	pub type Feeders<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxFeeders>, ValueQuery>;
+
+	/// The values submitted by feeders in the current block.
+	#[pallet::storage]
+	pub type Submissions<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(T::AccountId, u32), T::MaxFeeders>, ValueQuery>;
+
+	/// The number of oracle rounds finalized so far.
+	#[pallet::storage]
+	pub type OracleRound<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The most recent writes of values, in a ring buffer of `HistoryDepth` slots.
+	///
+	/// Write number `n` is recorded in slot `n % HistoryDepth`.
+	#[pallet::storage]
+	pub type History<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, HistoryItem<T::AccountId, T::BlockNumber>>;
+
+	/// The number of writes recorded in the history so far.
+	#[pallet::storage]
+	pub type HistoryCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+	/// Whether user calls are paused.
+	#[pallet::storage]
+	pub type Paused<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// Initial values stored for the given accounts.
+		pub something: Vec<(T::AccountId, u32)>,
+		/// The accounts whose keys can sign unsigned submissions.
+		pub authorities: Vec<T::AccountId>,
+		pub phantom: PhantomData<I>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self { something: Vec::new(), authorities: Vec::new(), phantom: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			for (who, something) in &self.something {
+				<Something<T, I>>::insert(who, something);
+			}
+			let authorities =
+				BoundedVec::<_, T::MaxAuthorities>::try_from(self.authorities.clone())
+					.expect("more initial authorities than `MaxAuthorities`");
+			<Authorities<T, I>>::put(authorities);
+		}
+	}
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
+		/// The value stored by an account was removed. [who]
+		SomethingCleared { who: T::AccountId },
+		/// The value stored by an account expired and was removed. [who]
+		Expired { who: T::AccountId },
+		/// An account scheduled its value to be updated at a future block. [something, who, at]
+		SomethingScheduled { something: u32, who: T::AccountId, at: T::BlockNumber },
+		/// A scheduled update was applied to an account's value. [something, who]
+		ScheduledApplied { something: u32, who: T::AccountId },
+		/// An account cancelled the update it scheduled. [who, at]
+		ScheduledCancelled { who: T::AccountId, at: T::BlockNumber },
+		/// A scheduled update could not be applied and was dropped. [who, error]
+		ScheduledFailed { who: T::AccountId, error: DispatchError },
+		/// An account set a registry entry. [who, key, value]
+		EntrySet { who: T::AccountId, key: KeyOf<T, I>, value: ValueOf<T, I> },
+		/// An account removed a registry entry. [who, key]
+		EntryRemoved { who: T::AccountId, key: KeyOf<T, I> },
+		/// An account removed all its registry entries. [who, count]
+		EntriesCleared { who: T::AccountId, count: u32 },
+		/// A commit-reveal phase started. [phase, ends_at]
+		PhaseStarted { phase: RoundPhase, ends_at: T::BlockNumber },
+		/// An account committed to the hash of a value. [who, hash]
+		Committed { who: T::AccountId, hash: H256 },
+		/// An account revealed the value it committed to. [who, something]
+		Revealed { who: T::AccountId, something: u32 },
+		/// The deposit of a commitment that was not revealed in time was slashed. [who, amount]
+		CommitSlashed { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// The write mode of `do_something` was changed. [mode]
+		ModeSet { mode: WriteMode },
+		/// An account was allowed to submit oracle values. [who]
+		FeederAdded { who: T::AccountId },
+		/// An account was no longer allowed to submit oracle values. [who]
+		FeederRemoved { who: T::AccountId },
+		/// A feeder submitted a value for the current oracle round. [something, who]
+		ValueSubmitted { something: u32, who: T::AccountId },
+		/// The median of an oracle round's submissions was stored for the oracle account.
+		/// [round, something, submissions, outliers]
+		RoundFinalized { round: u32, something: u32, submissions: u32, outliers: u32 },
+		/// User calls were paused.
+		Paused,
+		/// User calls were unpaused.
+		Unpaused,
+		/// An account's keys were allowed to sign unsigned submissions. [who]
+		AuthorityAdded { who: T::AccountId },
+		/// An account's keys were no longer allowed to sign unsigned submissions. [who]
+		AuthorityRemoved { who: T::AccountId },
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// Error names should be descriptive.
 		NoneValue,
 		/// Errors should have helpful documentation associated with them.
 		StorageOverflow,
+		/// The caller cannot afford the deposit for storing a value.
+		InsufficientBalance,
+		/// Values cannot expire because `MaxExpiringPerBlock` is zero.
+		TooManyExpiring,
+		/// Updates can only be scheduled for future blocks.
+		ScheduleInPast,
+		/// Too many updates are already scheduled for the block.
+		TooManyScheduled,
+		/// The caller already scheduled an update for the block.
+		AlreadyScheduled,
+		/// The caller has no update scheduled for the block.
+		NotScheduled,
+		/// The increment is larger than `MaxIncrementStep`.
+		IncrementTooLarge,
+		/// The caller made `MaxWritesPerPeriod` writes in the current period already.
+		RateLimited,
+		/// The caller already holds `MaxEntries` registry entries.
+		TooManyEntries,
+		/// The caller has no registry entry under the key, or none at all.
+		NoEntry,
+		/// Commitments are only accepted in the commit phase.
+		NotCommitPhase,
+		/// Values can only be revealed in the reveal phase.
+		NotRevealPhase,
+		/// The caller already committed in this round.
+		AlreadyCommitted,
+		/// The round already holds `MaxCommits` commitments.
+		TooManyCommits,
+		/// The caller has no commitment to reveal.
+		NoCommitment,
+		/// The revealed value and salt do not match the commitment.
+		InvalidReveal,
+		/// The account is a feeder already.
+		AlreadyFeeder,
+		/// The account is not a feeder.
+		NotFeeder,
+		/// There are `MaxFeeders` feeders already.
+		TooManyFeeders,
+		/// The caller submitted a value in this round already.
+		AlreadySubmitted,
+		/// User calls are paused.
+		Paused,
+		/// The account is an authority already.
+		AlreadyAuthority,
+		/// The account is not an authority.
+		NotAuthority,
+		/// There are `MaxAuthorities` authorities already.
+		TooManyAuthorities,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Apply the value updates scheduled for this block and advance the commit-reveal round.
+		///
+		/// In oracle mode, this also accounts for finalizing the round in `on_finalize`.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let scheduled = <Scheduled<T, I>>::take(now);
+			for (who, something) in &scheduled {
+				// Roll back a failed update without affecting the others.
+				let result = with_storage_layer(|| Self::store(who, *something));
+				match result {
+					Ok(_) => Self::deposit_event(Event::ScheduledApplied {
+						something: *something,
+						who: who.clone(),
+					}),
+					Err(error) =>
+						Self::deposit_event(Event::ScheduledFailed { who: who.clone(), error }),
+				}
+			}
+			let finalize_weight = match <Mode<T, I>>::get() {
+				WriteMode::Direct => T::DbWeight::get().reads(2),
+				WriteMode::Oracle => T::WeightInfo::finalize_round(T::MaxFeeders::get()),
+			};
+			T::WeightInfo::apply_scheduled(scheduled.len() as u32)
+				.saturating_add(Self::advance_phase(now))
+				.saturating_add(finalize_weight)
+		}
+
+		/// Store the median of this block's oracle submissions, if any.
+		fn on_finalize(_n: T::BlockNumber) {
+			Self::finalize_round();
+		}
+
+		/// Purge expired values with the weight left in the block, oldest first.
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::from_limit(remaining_weight);
+			if !meter.check_accrue(T::WeightInfo::on_idle_base()) {
+				return Weight::zero()
+			}
+
+			let mut cursor = <NextExpiryBlock<T, I>>::get();
+			if cursor.is_zero() {
+				// The cursor is set when the first value is queued, so nothing is queued yet.
+				return meter.consumed
+			}
+			let max_purge_weight = T::WeightInfo::purge_expired(T::MaxExpiringPerBlock::get());
+			while cursor <= now && meter.can_accrue(max_purge_weight) {
+				let queued = Self::purge_expired(cursor);
+				let accrued = meter.check_accrue(T::WeightInfo::purge_expired(queued));
+				debug_assert!(accrued, "the queue is bounded by `MaxExpiringPerBlock`; qed");
+				cursor.saturating_inc();
+			}
+			<NextExpiryBlock<T, I>>::put(cursor);
+
+			meter.consumed
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+
+		/// Every `OffchainInterval` blocks, fetch a value over HTTP and submit it on-chain.
+		fn offchain_worker(block_number: T::BlockNumber) {
+			let interval = T::OffchainInterval::get();
+			if interval.is_zero() || !(block_number % interval).is_zero() {
+				return
+			}
+
+			if let Err(e) = Self::fetch_and_submit(block_number) {
+				log::error!(target: LOG_TARGET, "offchain worker failed: {}", e);
+			}
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
+		/// the caller's storage entry and emits an event. This function must be dispatched by a
+		/// signed extrinsic. `SomethingDeposit` is reserved from the caller for a new entry, and
+		/// the caller pays no fee for storing it.
+		///
+		/// In oracle mode, the value is instead submitted for the current round and the caller
+		/// must be a feeder.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::do_something())]
-		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
+		#[pallet::weight(
+			T::WeightInfo::do_something_insert()
+				.max(T::WeightInfo::do_something_update())
+				.max(T::WeightInfo::submit_value())
+		)]
+		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResultWithPostInfo {
 			// Check that the extrinsic was signed and get the signer.
 			// This function will return an error if the extrinsic is not signed.
 			// https://docs.substrate.io/main-docs/build/origins/
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			Self::note_write(&who)?;
 
-			// Update storage.
-			<Something<T>>::put(something);
+			if <Mode<T, I>>::get() == WriteMode::Oracle {
+				Self::submit_value(who, something)?;
+				return Ok(Some(T::WeightInfo::submit_value()).into())
+			}
+
+			// Update storage, reserving a deposit for a new entry.
+			let is_new = Self::store(&who, something)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::SomethingStored { something, who });
-			// Return a successful DispatchResultWithPostInfo
-			Ok(())
+			// Return a successful DispatchResultWithPostInfo. The deposit pays for new entries.
+			if is_new {
+				Ok(PostDispatchInfo {
+					actual_weight: Some(T::WeightInfo::do_something_insert()),
+					pays_fee: Pays::No,
+				})
+			} else {
+				Ok(Some(T::WeightInfo::do_something_update()).into())
+			}
 		}
 
-		/// An example dispatchable that may throw a custom error.
+		/// An example dispatchable that may throw a custom error. Overflow is handled according
+		/// to `OverflowPolicy`. Failed calls are only charged for the read.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::cause_error())]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
+		#[pallet::weight(T::WeightInfo::cause_error().max(T::WeightInfo::cause_error_failed()))]
+		pub fn cause_error(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let failed_weight = T::WeightInfo::cause_error_failed();
+			Self::ensure_not_paused().map_err(|e| e.with_weight(failed_weight))?;
+			Self::note_write(&who).map_err(|e| e.with_weight(failed_weight))?;
 
-			// Read a value from storage.
-			match <Something<T>>::get() {
+			// Read the caller's value from storage.
+			match <Something<T, I>>::get(&who) {
 				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
+				None => return Err(Error::<T, I>::NoneValue.with_weight(failed_weight)),
 				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					// Increment the value read from storage; may error in the event of overflow.
+					let new = T::OverflowPolicy::increment(old, 1)
+						.ok_or(Error::<T, I>::StorageOverflow.with_weight(failed_weight))?;
 					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
+					<Something<T, I>>::insert(&who, new);
+					Self::record_history(&who, new);
+					Ok(Some(T::WeightInfo::cause_error()).into())
 				},
 			}
 		}
+
+		/// Remove the value stored by the caller and release its deposit.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::clear_something())]
+		pub fn clear_something(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+
+			ensure!(<Something<T, I>>::contains_key(&who), Error::<T, I>::NoneValue);
+			<Something<T, I>>::remove(&who);
+			<ExpiresAt<T, I>>::remove(&who);
+			Self::release_deposit(&who);
+
+			Self::deposit_event(Event::SomethingCleared { who });
+			Ok(())
+		}
+
+		/// Store a value fetched by the offchain worker for the account of the key that signed
+		/// `payload`, which must be one of the `Authorities`. The signature is checked when the
+		/// transaction is validated.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::submit_something_unsigned())]
+		pub fn submit_something_unsigned(
+			origin: OriginFor<T>,
+			payload: SomethingPayload<T::Public, T::BlockNumber>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::ensure_not_paused()?;
+
+			let SomethingPayload { something, public, .. } = payload;
+			let who = public.into_account();
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			<Something<T, I>>::insert(&who, something);
+			Self::record_history(&who, something);
+			Self::schedule_expiry(&who)?;
+
+			// Throttle unsigned submissions so they cannot fill up the blocks for free.
+			let now = <frame_system::Pallet<T>>::block_number();
+			<NextUnsignedAt<T, I>>::put(now.saturating_add(T::OffchainInterval::get()));
+
+			Self::deposit_event(Event::SomethingStored { something, who });
+			Ok(())
+		}
+
+		/// Set the value stored for `who`. Must be dispatched by `AdminOrigin`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::force_set_something())]
+		pub fn force_set_something(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			something: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Something<T, I>>::insert(&who, something);
+			Self::record_history(&who, something);
+			Self::schedule_expiry(&who)?;
+
+			Self::deposit_event(Event::SomethingStored { something, who });
+			Ok(())
+		}
+
+		/// Remove the value stored for `who` and release its deposit, if any. Must be dispatched by
+		/// `AdminOrigin`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::force_clear_something())]
+		pub fn force_clear_something(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(<Something<T, I>>::contains_key(&who), Error::<T, I>::NoneValue);
+			<Something<T, I>>::remove(&who);
+			<ExpiresAt<T, I>>::remove(&who);
+			Self::release_deposit(&who);
+
+			Self::deposit_event(Event::SomethingCleared { who });
+			Ok(())
+		}
+
+		/// Schedule the caller's value to be set to `something` at the start of block `at`.
+		///
+		/// The deposit for a new entry is reserved when the update is applied.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::schedule_something(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_something(
+			origin: OriginFor<T>,
+			something: u32,
+			at: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(at > <frame_system::Pallet<T>>::block_number(), Error::<T, I>::ScheduleInPast);
+			Self::note_write(&who)?;
+
+			let queued = <Scheduled<T, I>>::try_mutate(at, |scheduled| {
+				ensure!(
+					!scheduled.iter().any(|(account, _)| account == &who),
+					Error::<T, I>::AlreadyScheduled
+				);
+				let queued = scheduled.len() as u32;
+				scheduled
+					.try_push((who.clone(), something))
+					.map_err(|_| Error::<T, I>::TooManyScheduled)?;
+				Ok::<_, DispatchError>(queued)
+			})?;
+
+			Self::deposit_event(Event::SomethingScheduled { something, who, at });
+			Ok(Some(T::WeightInfo::schedule_something(queued)).into())
+		}
+
+		/// Cancel the update the caller scheduled for block `at`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_scheduled(T::MaxScheduledPerBlock::get()))]
+		pub fn cancel_scheduled(
+			origin: OriginFor<T>,
+			at: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+
+			let queued = <Scheduled<T, I>>::try_mutate_exists(at, |maybe_scheduled| {
+				let scheduled = maybe_scheduled.as_mut().ok_or(Error::<T, I>::NotScheduled)?;
+				let queued = scheduled.len() as u32;
+				let index = scheduled
+					.iter()
+					.position(|(account, _)| account == &who)
+					.ok_or(Error::<T, I>::NotScheduled)?;
+				scheduled.remove(index);
+				if scheduled.is_empty() {
+					*maybe_scheduled = None;
+				}
+				Ok::<_, DispatchError>(queued)
+			})?;
+
+			Self::deposit_event(Event::ScheduledCancelled { who, at });
+			Ok(Some(T::WeightInfo::cancel_scheduled(queued)).into())
+		}
+
+		/// Increment the caller's value by `by`, which must not exceed `MaxIncrementStep`.
+		/// Overflow is handled according to `OverflowPolicy`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::increment_by())]
+		pub fn increment_by(origin: OriginFor<T>, by: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(by <= T::MaxIncrementStep::get(), Error::<T, I>::IncrementTooLarge);
+			Self::note_write(&who)?;
+
+			let something = <Something<T, I>>::try_mutate(&who, |value| {
+				let old = value.ok_or(Error::<T, I>::NoneValue)?;
+				let new =
+					T::OverflowPolicy::increment(old, by).ok_or(Error::<T, I>::StorageOverflow)?;
+				*value = Some(new);
+				Ok::<_, DispatchError>(new)
+			})?;
+			Self::record_history(&who, something);
+
+			Self::deposit_event(Event::SomethingStored { something, who });
+			Ok(())
+		}
+
+		/// Set the caller's registry entry under `key` to `value`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_entry(key.len() as u32, value.len() as u32))]
+		pub fn set_entry(
+			origin: OriginFor<T>,
+			key: KeyOf<T, I>,
+			value: ValueOf<T, I>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			Self::note_write(&who)?;
+
+			if !<Entries<T, I>>::contains_key(&who, &key) {
+				<EntryCount<T, I>>::try_mutate(&who, |count| {
+					ensure!(*count < T::MaxEntries::get(), Error::<T, I>::TooManyEntries);
+					*count += 1;
+					Ok::<_, DispatchError>(())
+				})?;
+			}
+			<Entries<T, I>>::insert(&who, &key, &value);
+
+			Self::deposit_event(Event::EntrySet { who, key, value });
+			Ok(())
+		}
+
+		/// Remove the caller's registry entry under `key`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::remove_entry(key.len() as u32))]
+		pub fn remove_entry(origin: OriginFor<T>, key: KeyOf<T, I>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+
+			ensure!(<Entries<T, I>>::contains_key(&who, &key), Error::<T, I>::NoEntry);
+			<Entries<T, I>>::remove(&who, &key);
+			let count = <EntryCount<T, I>>::get(&who).saturating_sub(1);
+			if count.is_zero() {
+				<EntryCount<T, I>>::remove(&who);
+			} else {
+				<EntryCount<T, I>>::insert(&who, count);
+			}
+
+			Self::deposit_event(Event::EntryRemoved { who, key });
+			Ok(())
+		}
+
+		/// Remove all the caller's registry entries.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::clear_all(T::MaxEntries::get()))]
+		pub fn clear_all(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+
+			let count = <EntryCount<T, I>>::take(&who);
+			ensure!(count > 0, Error::<T, I>::NoEntry);
+			// The count bounds the number of entries, so they are all removed at once.
+			let _ = <Entries<T, I>>::clear_prefix(&who, count, None);
+
+			Self::deposit_event(Event::EntriesCleared { who, count });
+			Ok(Some(T::WeightInfo::clear_all(count)).into())
+		}
+
+		/// Commit to `hash`, as computed by [`Pallet::commitment`], reserving `CommitDeposit`.
+		/// Must be called in the commit phase.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::commit())]
+		pub fn commit(origin: OriginFor<T>, hash: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(
+				matches!(<CurrentPhase<T, I>>::get(), Some((RoundPhase::Commit, _))),
+				Error::<T, I>::NotCommitPhase
+			);
+			ensure!(!<Commits<T, I>>::contains_key(&who), Error::<T, I>::AlreadyCommitted);
+			<CommitCount<T, I>>::try_mutate(|count| {
+				ensure!(*count < T::MaxCommits::get(), Error::<T, I>::TooManyCommits);
+				*count += 1;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			let deposit = T::CommitDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T, I>::InsufficientBalance)?;
+			<Commits<T, I>>::insert(&who, (hash, deposit));
+
+			Self::deposit_event(Event::Committed { who, hash });
+			Ok(())
+		}
+
+		/// Reveal the value and salt committed to, storing the value for the caller and releasing
+		/// the commitment's deposit. Must be called in the reveal phase.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::reveal())]
+		pub fn reveal(origin: OriginFor<T>, something: u32, salt: [u8; 32]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(
+				matches!(<CurrentPhase<T, I>>::get(), Some((RoundPhase::Reveal, _))),
+				Error::<T, I>::NotRevealPhase
+			);
+			let (hash, deposit) = <Commits<T, I>>::get(&who).ok_or(Error::<T, I>::NoCommitment)?;
+			ensure!(hash == Self::commitment(&who, something, &salt), Error::<T, I>::InvalidReveal);
+
+			<Commits<T, I>>::remove(&who);
+			<CommitCount<T, I>>::mutate(|count| count.saturating_dec());
+			let _ = T::Currency::unreserve(&who, deposit);
+			Self::store(&who, something)?;
+
+			Self::deposit_event(Event::Revealed { who, something });
+			Ok(())
+		}
+
+		/// Set how `do_something` writes values. Must be dispatched by `AdminOrigin`.
+		///
+		/// Switching to oracle mode allows a round to be finalized in this block, which
+		/// `on_initialize` did not account for, so its weight is charged here.
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			T::WeightInfo::set_mode().saturating_add(T::WeightInfo::finalize_round(T::MaxFeeders::get()))
+		)]
+		pub fn set_mode(origin: OriginFor<T>, mode: WriteMode) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Mode<T, I>>::put(mode);
+
+			Self::deposit_event(Event::ModeSet { mode });
+			Ok(())
+		}
+
+		/// Allow `who` to submit values in oracle mode. Must be dispatched by `AdminOrigin`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::add_feeder())]
+		pub fn add_feeder(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Feeders<T, I>>::try_mutate(|feeders| {
+				ensure!(!feeders.contains(&who), Error::<T, I>::AlreadyFeeder);
+				feeders.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyFeeders)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::FeederAdded { who });
+			Ok(())
+		}
+
+		/// Stop `who` from submitting values in oracle mode. Its submission for the current round,
+		/// if any, still counts. Must be dispatched by `AdminOrigin`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_feeder())]
+		pub fn remove_feeder(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Feeders<T, I>>::try_mutate(|feeders| {
+				let index =
+					feeders.iter().position(|f| f == &who).ok_or(Error::<T, I>::NotFeeder)?;
+				feeders.remove(index);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::FeederRemoved { who });
+			Ok(())
+		}
+
+		/// Make all user calls fail until `unpause` is called. Must be dispatched by
+		/// `AdminOrigin`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Paused<T, I>>::put(true);
+
+			Self::deposit_event(Event::Paused);
+			Ok(())
+		}
+
+		/// Allow user calls again. Must be dispatched by `AdminOrigin`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Paused<T, I>>::kill();
+
+			Self::deposit_event(Event::Unpaused);
+			Ok(())
+		}
+
+		/// Allow the keys of `who` to sign unsigned submissions. Must be dispatched by
+		/// `AdminOrigin`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::add_authority())]
+		pub fn add_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Authorities<T, I>>::try_mutate(|authorities| {
+				ensure!(!authorities.contains(&who), Error::<T, I>::AlreadyAuthority);
+				authorities
+					.try_push(who.clone())
+					.map_err(|_| Error::<T, I>::TooManyAuthorities)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::AuthorityAdded { who });
+			Ok(())
+		}
+
+		/// Stop the keys of `who` from signing unsigned submissions. Must be dispatched by
+		/// `AdminOrigin`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::remove_authority())]
+		pub fn remove_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Authorities<T, I>>::try_mutate(|authorities| {
+				let index = authorities
+					.iter()
+					.position(|a| a == &who)
+					.ok_or(Error::<T, I>::NotAuthority)?;
+				authorities.remove(index);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::AuthorityRemoved { who });
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Event<T, I> {
+		/// The topics the event is indexed by: the account it concerns and, for registry events,
+		/// the key. See [`crate::topics`].
+		pub fn topics(&self) -> Vec<T::Hash> {
+			let account = |who: &T::AccountId| crate::topics::account::<T::Hashing, _>(who);
+			match self {
+				Event::SomethingStored { who, .. } |
+				Event::SomethingCleared { who } |
+				Event::Expired { who } |
+				Event::SomethingScheduled { who, .. } |
+				Event::ScheduledApplied { who, .. } |
+				Event::ScheduledCancelled { who, .. } |
+				Event::ScheduledFailed { who, .. } |
+				Event::EntriesCleared { who, .. } |
+				Event::Committed { who, .. } |
+				Event::Revealed { who, .. } |
+				Event::CommitSlashed { who, .. } |
+				Event::FeederAdded { who } |
+				Event::FeederRemoved { who } |
+				Event::AuthorityAdded { who } |
+				Event::AuthorityRemoved { who } |
+				Event::ValueSubmitted { who, .. } => vec![account(who)],
+				Event::EntrySet { who, key, .. } | Event::EntryRemoved { who, key } =>
+					vec![account(who), crate::topics::key::<T::Hashing>(key)],
+				// The median is stored for the oracle account.
+				Event::RoundFinalized { .. } => vec![account(&Pallet::<T, I>::oracle_account())],
+				_ => Vec::new(),
+			}
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Deposit `event`, indexed by its [`Event::topics`] so clients can find the events
+		/// concerning an account without scanning every event.
+		pub(super) fn deposit_event(event: Event<T, I>) {
+			let topics = event.topics();
+			let event = <T as Config<I>>::RuntimeEvent::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+		}
+
+		/// Store `something` for `who` as if set by `who` itself, reserving a deposit for a new
+		/// entry and scheduling its expiry. Returns whether a deposit was reserved.
+		fn store(who: &T::AccountId, something: u32) -> Result<bool, DispatchError> {
+			let is_new = Self::hold_deposit(who)?;
+			<Something<T, I>>::insert(who, something);
+			Self::record_history(who, something);
+			Self::schedule_expiry(who)?;
+			Ok(is_new)
+		}
+
+		/// Record that `something` was written for `who` in the history, overwriting the oldest
+		/// write once the history is full.
+		fn record_history(who: &T::AccountId, something: u32) {
+			let depth = T::HistoryDepth::get();
+			if depth.is_zero() {
+				return
+			}
+
+			let count = <HistoryCount<T, I>>::get();
+			let slot = (count % depth as u64) as u32;
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			<History<T, I>>::insert(
+				slot,
+				HistoryItem { block_number, who: who.clone(), something },
+			);
+			<HistoryCount<T, I>>::put(count.saturating_add(1));
+		}
+
+		/// The writes recorded in the history, oldest first.
+		pub fn history() -> Vec<HistoryItem<T::AccountId, T::BlockNumber>> {
+			let depth = T::HistoryDepth::get() as u64;
+			if depth.is_zero() {
+				return Vec::new()
+			}
+
+			let count = <HistoryCount<T, I>>::get();
+			(count.saturating_sub(depth)..count)
+				.filter_map(|n| <History<T, I>>::get((n % depth) as u32))
+				.collect()
+		}
+
+		/// Reserve `SomethingDeposit` from `who` unless it already holds a deposit. Returns whether
+		/// a deposit was reserved.
+		fn hold_deposit(who: &T::AccountId) -> Result<bool, DispatchError> {
+			if <Deposits<T, I>>::contains_key(who) {
+				return Ok(false)
+			}
+
+			let deposit = T::SomethingDeposit::get();
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T, I>::InsufficientBalance)?;
+			<Deposits<T, I>>::insert(who, deposit);
+			Ok(true)
+		}
+
+		/// Queue the value just stored by `who` to expire `SomethingTtl` blocks from now.
+		fn schedule_expiry(who: &T::AccountId) -> DispatchResult {
+			let ttl = T::SomethingTtl::get();
+			if ttl.is_zero() {
+				return Ok(())
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let earliest = now.saturating_add(ttl);
+			// Already queued by an earlier write in this block, or spilled past it.
+			if <ExpiresAt<T, I>>::get(who).map_or(false, |at| at >= earliest) {
+				return Ok(())
+			}
+			// Queue behind the latest value so a full queue spills into the next block rather
+			// than rejecting the write.
+			let mut expires_at = earliest.max(<LastExpiryBlock<T, I>>::get());
+			if <ExpiryQueue<T, I>>::decode_len(expires_at).unwrap_or(0) >=
+				T::MaxExpiringPerBlock::get() as usize
+			{
+				expires_at = expires_at.saturating_add(One::one());
+			}
+			<ExpiryQueue<T, I>>::try_append(expires_at, who)
+				.map_err(|_| Error::<T, I>::TooManyExpiring)?;
+			<LastExpiryBlock<T, I>>::put(expires_at);
+			<ExpiresAt<T, I>>::insert(who, expires_at);
+			// Start purging from the block the first value was queued in, however late `on_idle`
+			// first gets to run. Values always expire after the block they were stored in.
+			if <NextExpiryBlock<T, I>>::get().is_zero() {
+				<NextExpiryBlock<T, I>>::put(now);
+			}
+			Ok(())
+		}
+
+		/// Remove the values expiring at `block`, releasing their deposits. Returns the number of
+		/// queued entries, including skipped ones.
+		pub(crate) fn purge_expired(block: T::BlockNumber) -> u32 {
+			let queue = <ExpiryQueue<T, I>>::take(block);
+			for who in &queue {
+				// The value was rewritten or cleared since it was queued.
+				if <ExpiresAt<T, I>>::get(who) != Some(block) {
+					continue
+				}
+
+				<ExpiresAt<T, I>>::remove(who);
+				<Something<T, I>>::remove(who);
+				Self::release_deposit(who);
+				Self::deposit_event(Event::Expired { who: who.clone() });
+			}
+			queue.len() as u32
+		}
+
+		/// Whether `call` is a user call, which fails while paused. Only `AdminOrigin` calls are
+		/// not.
+		pub fn is_user_call(call: &Call<T, I>) -> bool {
+			!matches!(
+				call,
+				Call::force_set_something { .. } |
+					Call::force_clear_something { .. } |
+					Call::set_mode { .. } |
+					Call::add_feeder { .. } |
+					Call::remove_feeder { .. } |
+					Call::pause { .. } |
+					Call::unpause { .. } |
+					Call::add_authority { .. } |
+					Call::remove_authority { .. }
+			)
+		}
+
+		/// Fail if user calls are paused.
+		fn ensure_not_paused() -> DispatchResult {
+			ensure!(!<Paused<T, I>>::get(), Error::<T, I>::Paused);
+			Ok(())
+		}
+
+		/// Whether `call` counts against the caller's `MaxWritesPerPeriod`.
+		pub fn is_rate_limited(call: &Call<T, I>) -> bool {
+			matches!(
+				call,
+				Call::do_something { .. } |
+					Call::cause_error { .. } |
+					Call::schedule_something { .. } |
+					Call::increment_by { .. } |
+					Call::set_entry { .. }
+			)
+		}
+
+		/// Whether `who` can make another write in the current period.
+		pub fn can_write(who: &T::AccountId) -> bool {
+			let Some(period_start) = Self::current_period_start() else { return true };
+			let (last_period_start, writes) = <WriteCounts<T, I>>::get(who);
+			last_period_start != period_start || writes < T::MaxWritesPerPeriod::get()
+		}
+
+		/// Count a write by `who` against its limit for the current period.
+		fn note_write(who: &T::AccountId) -> DispatchResult {
+			let Some(period_start) = Self::current_period_start() else { return Ok(()) };
+			<WriteCounts<T, I>>::try_mutate(who, |(last_period_start, writes)| {
+				if *last_period_start != period_start {
+					*last_period_start = period_start;
+					*writes = 0;
+				}
+				ensure!(*writes < T::MaxWritesPerPeriod::get(), Error::<T, I>::RateLimited);
+				*writes += 1;
+				Ok(())
+			})
+		}
+
+		/// The first block of the current rate-limiting period, or `None` if rate limiting is
+		/// disabled.
+		fn current_period_start() -> Option<T::BlockNumber> {
+			let period = T::Period::get();
+			if period.is_zero() {
+				return None
+			}
+			let now = <frame_system::Pallet<T>>::block_number();
+			Some(now - now % period)
+		}
+
+		/// The account the oracle's value is stored for.
+		pub fn oracle_account() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Submit `something` from feeder `who` for the current oracle round.
+		fn submit_value(who: T::AccountId, something: u32) -> DispatchResult {
+			ensure!(<Feeders<T, I>>::get().contains(&who), Error::<T, I>::NotFeeder);
+			<Submissions<T, I>>::try_mutate(|submissions| {
+				ensure!(
+					!submissions.iter().any(|(feeder, _)| feeder == &who),
+					Error::<T, I>::AlreadySubmitted
+				);
+				// Feeders submit at most once per round, so this is within the bound.
+				submissions
+					.try_push((who.clone(), something))
+					.map_err(|_| Error::<T, I>::TooManyFeeders)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ValueSubmitted { something, who });
+			Ok(())
+		}
+
+		/// Store the median of the current round's submissions for the oracle account, ignoring
+		/// outliers, and start a new round. Does nothing if there are no submissions.
+		pub(crate) fn finalize_round() {
+			if !<Submissions<T, I>>::exists() {
+				return
+			}
+			let submissions = <Submissions<T, I>>::take();
+
+			let mut values: Vec<u32> = submissions.iter().map(|(_, value)| *value).collect();
+			values.sort_unstable();
+			let median = Self::median(&values);
+			let max_deviation = T::MaxDeviation::get().mul_floor(median);
+			let kept: Vec<u32> =
+				values.iter().copied().filter(|v| v.abs_diff(median) <= max_deviation).collect();
+			// With an even number of submissions, all of them can be far from the median.
+			let (something, outliers) = if kept.is_empty() {
+				(median, 0)
+			} else {
+				(Self::median(&kept), (values.len() - kept.len()) as u32)
+			};
+
+			let oracle = Self::oracle_account();
+			<Something<T, I>>::insert(&oracle, something);
+			Self::record_history(&oracle, something);
+			let round = <OracleRound<T, I>>::mutate(|round| {
+				let finalized = *round;
+				round.saturating_inc();
+				finalized
+			});
+			Self::deposit_event(Event::RoundFinalized {
+				round,
+				something,
+				submissions: values.len() as u32,
+				outliers,
+			});
+		}
+
+		/// The median of the non-empty, sorted `values`.
+		fn median(values: &[u32]) -> u32 {
+			let mid = values.len() / 2;
+			if values.len() % 2 == 1 {
+				values[mid]
+			} else {
+				let (low, high) = (values[mid - 1], values[mid]);
+				low + (high - low) / 2
+			}
+		}
+
+		/// The commitment `who` has to submit to reveal `something` with `salt` later.
+		///
+		/// The account is part of the preimage, so commitments cannot be copied by others.
+		pub fn commitment(who: &T::AccountId, something: u32, salt: &[u8; 32]) -> H256 {
+			BlakeTwo256::hash_of(&(who, something, salt))
+		}
+
+		/// Move to the next commit-reveal phase if the current one ended at `now`, slashing the
+		/// commitments left unrevealed when a round ends.
+		pub(crate) fn advance_phase(now: T::BlockNumber) -> Weight {
+			let commit_length = T::CommitPhaseLength::get();
+			if commit_length.is_zero() {
+				return Weight::zero()
+			}
+
+			let (phase, ends_at, weight) = match <CurrentPhase<T, I>>::get() {
+				Some((_, ends_at)) if ends_at > now => return T::DbWeight::get().reads(1),
+				Some((RoundPhase::Commit, _)) => (
+					RoundPhase::Reveal,
+					now.saturating_add(T::RevealPhaseLength::get()),
+					T::WeightInfo::start_reveal_phase(),
+				),
+				Some((RoundPhase::Reveal, _)) | None => {
+					let slashed = Self::slash_unrevealed();
+					(
+						RoundPhase::Commit,
+						now.saturating_add(commit_length),
+						T::WeightInfo::start_commit_phase(slashed),
+					)
+				},
+			};
+			<CurrentPhase<T, I>>::put((phase, ends_at));
+			Self::deposit_event(Event::PhaseStarted { phase, ends_at });
+			weight
+		}
+
+		/// Slash the deposits of all remaining commitments. Returns the number of commitments.
+		fn slash_unrevealed() -> u32 {
+			let mut slashed = 0;
+			for (who, (_, deposit)) in <Commits<T, I>>::drain() {
+				let (imbalance, _) = T::Currency::slash_reserved(&who, deposit);
+				T::Slashed::on_unbalanced(imbalance);
+				Self::deposit_event(Event::CommitSlashed { who, amount: deposit });
+				slashed += 1;
+			}
+			<CommitCount<T, I>>::kill();
+			slashed
+		}
+
+		/// Release the deposit held by `who`, if any.
+		fn release_deposit(who: &T::AccountId) {
+			if let Some(deposit) = <Deposits<T, I>>::take(who) {
+				let _ = T::Currency::unreserve(who, deposit);
+			}
+		}
+	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Check the invariants of the pallet's storage.
+		///
+		/// - Every deposit is held for a stored value and is covered by the account's reserved
+		///   balance.
+		/// - Every value with an expiry is queued to expire at that block, and that block has not
+		///   been purged yet.
+		/// - No expiry queue is left behind the purge cursor.
+		/// - No scheduled update is left behind the current block.
+		/// - Unsigned submissions are throttled for at most one interval.
+		/// - Every account's entry count matches its registry entries and is within `MaxEntries`.
+		/// - The commitment count matches the commitments and is within `MaxCommits`.
+		/// - The history only uses its `HistoryDepth` slots.
+		pub fn do_try_state() -> Result<(), &'static str> {
+			for (who, deposit) in <Deposits<T, I>>::iter() {
+				ensure!(<Something<T, I>>::contains_key(&who), "deposit held without a value");
+				ensure!(
+					T::Currency::reserved_balance(&who) >= deposit,
+					"deposit exceeds the reserved balance"
+				);
+			}
+
+			let cursor = <NextExpiryBlock<T, I>>::get();
+			for (who, expires_at) in <ExpiresAt<T, I>>::iter() {
+				ensure!(<Something<T, I>>::contains_key(&who), "expiry set without a value");
+				ensure!(expires_at >= cursor, "value not purged at its expiry");
+				ensure!(
+					<ExpiryQueue<T, I>>::get(expires_at).contains(&who),
+					"value not queued at its expiry"
+				);
+			}
+			for expires_at in <ExpiryQueue<T, I>>::iter_keys() {
+				ensure!(expires_at >= cursor, "expiry queue left behind the purge cursor");
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			for at in <Scheduled<T, I>>::iter_keys() {
+				ensure!(at > now, "scheduled update not applied");
+			}
+			ensure!(
+				<NextUnsignedAt<T, I>>::get() <= now.saturating_add(T::OffchainInterval::get()),
+				"unsigned submissions throttled for more than an interval"
+			);
+
+			for (who, count) in <EntryCount<T, I>>::iter() {
+				ensure!(count > 0, "zero entry count stored");
+				ensure!(count <= T::MaxEntries::get(), "too many entries");
+				ensure!(
+					<Entries<T, I>>::iter_prefix(&who).count() as u32 == count,
+					"entry count does not match the entries"
+				);
+			}
+			for who in <Entries<T, I>>::iter_keys().map(|(who, _)| who) {
+				ensure!(<EntryCount<T, I>>::contains_key(&who), "entries held without a count");
+			}
+
+			let commits = <CommitCount<T, I>>::get();
+			ensure!(commits <= T::MaxCommits::get(), "too many commitments");
+			ensure!(
+				<Commits<T, I>>::iter_keys().count() as u32 == commits,
+				"commitment count does not match the commitments"
+			);
+
+			let depth = T::HistoryDepth::get();
+			for slot in <History<T, I>>::iter_keys() {
+				ensure!(slot < depth, "history slot beyond the history depth");
+			}
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		/// Only accept unsigned submissions that are correctly signed by an authority, fetched no
+		/// earlier than `NextUnsignedAt` and not from the future.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (payload, signature) = match call {
+				Call::submit_something_unsigned { payload, signature } => (payload, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			if <Paused<T, I>>::get() {
+				return InvalidTransaction::Custom(PAUSED).into()
+			}
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			// Otherwise anyone could store values for free, bypassing `SomethingDeposit`.
+			if !<Authorities<T, I>>::get().contains(&payload.public.clone().into_account()) {
+				return InvalidTransaction::BadSigner.into()
+			}
+
+			let next_unsigned_at = <NextUnsignedAt<T, I>>::get();
+			if payload.block_number < next_unsigned_at {
+				return InvalidTransaction::Stale.into()
+			}
+			if payload.block_number > <frame_system::Pallet<T>>::block_number() {
+				return InvalidTransaction::Future.into()
+			}
+
+			ValidTransaction::with_tag_prefix("TemplateOffchainWorker")
+				.priority(T::UnsignedPriority::get())
+				// Only one unsigned submission per interval and instance can make it into a block.
+				.and_provides((<Self as PalletInfoAccess>::name(), next_unsigned_at))
+				.longevity(T::OffchainInterval::get().saturated_into::<u64>())
+				.propagate(true)
+				.build()
+		}
 	}
 }
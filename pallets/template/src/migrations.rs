@@ -0,0 +1,94 @@
+//! Storage migrations for pallet-template.
+//!
+//! Every change to the storage layout gets its own `vN` module containing a `MigrateToVN`
+//! [`OnRuntimeUpgrade`](frame_support::traits::OnRuntimeUpgrade) implementation. Each migration
+//! only runs when the on-chain [`StorageVersion`](frame_support::traits::StorageVersion) is the
+//! one it migrates from and bumps it when done, so migrations can safely be listed in the
+//! runtime's `Migrations` tuple, in order, for as long as chains on older versions exist.
+//!
+//! With the `try-runtime` feature enabled each migration checks its own preconditions in
+//! `pre_upgrade` and verifies the migrated state in `post_upgrade`.
+
+/// Migrate from a single global `Something` value to one value per account.
+pub mod v1 {
+	use crate::{Config, Pallet, Something};
+	use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	/// The storage layout before values were kept per account.
+	mod v0 {
+		use super::*;
+
+		#[storage_alias]
+		pub type Something<T: Config<I>, I: 'static> = StorageValue<Pallet<T, I>, u32>;
+	}
+
+	/// Moves the old global `Something` value into the entry of the account returned by `Owner`.
+	///
+	/// If `Owner` returns `None` the old value is dropped. The migration only runs on storage
+	/// version 0 and leaves the pallet instance `I` at storage version 1. Newly added instances
+	/// have no old value but still need their storage version set, which `Owner = ()` does.
+	pub struct MigrateToV1<T, Owner, I = ()>(PhantomData<(T, Owner, I)>);
+
+	impl<T: Config<I>, Owner: Get<Option<T::AccountId>>, I: 'static> OnRuntimeUpgrade
+		for MigrateToV1<T, Owner, I>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				log::info!(
+					target: crate::LOG_TARGET,
+					"skipping v1 migration: on-chain storage version is {:?}",
+					on_chain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut writes = 2;
+			match (v0::Something::<T, I>::take(), Owner::get()) {
+				(Some(old), Some(owner)) => {
+					Something::<T, I>::insert(owner, old);
+					writes += 1;
+				},
+				(Some(_), None) => {
+					log::warn!(target: crate::LOG_TARGET, "no owner for the old value, dropping it");
+				},
+				(None, _) => {},
+			}
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			log::info!(target: crate::LOG_TARGET, "migrated storage to v1");
+			T::DbWeight::get().reads_writes(2, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let old = if Pallet::<T, I>::on_chain_storage_version() == 0 {
+				v0::Something::<T, I>::get()
+			} else {
+				None
+			};
+			Ok((old, Owner::get()).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (old, owner): (Option<u32>, Option<T::AccountId>) = Decode::decode(&mut &state[..])
+				.map_err(|_| "v1: failed to decode pre-upgrade state")?;
+
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() >= 1,
+				"v1: storage version not bumped"
+			);
+			ensure!(!v0::Something::<T, I>::exists(), "v1: old global value still present");
+			if let (Some(old), Some(owner)) = (old, owner) {
+				ensure!(
+					Something::<T, I>::get(owner) == Some(old),
+					"v1: old value not moved to owner"
+				);
+			}
+			Ok(())
+		}
+	}
+}
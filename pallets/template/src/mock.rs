@@ -1,14 +1,26 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
-use sp_core::H256;
+use frame_support::{
+	instances::Instance1,
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, GenesisBuild},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use pallet_template::overflow::{Checked, OverflowPolicy, Saturating, Wrapping};
+use sp_core::{sr25519::Signature, H256};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestXt},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, Verify},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test where
@@ -17,7 +29,9 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_template,
+		SecondTemplateModule: pallet_template::<Instance1>,
 	}
 );
 
@@ -32,28 +46,227 @@ impl frame_system::Config for Test {
 	type BlockNumber = u64;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
-	type AccountId = u64;
+	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type HoldIdentifier = ();
+	type MaxHolds = ();
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: <Signature as Verify>::Signer,
+		_account: AccountId,
+		nonce: u64,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+/// The overflow policies the mock runtime can be switched between.
+#[derive(Clone, Copy, Debug)]
+pub enum Policy {
+	Checked,
+	Saturating,
+	Wrapping,
+}
+
+parameter_types! {
+	pub static IncrementPolicy: Policy = Policy::Checked;
+	pub const TemplatePalletId: PalletId = PalletId(*b"py/tmpl0");
+	pub const SecondTemplatePalletId: PalletId = PalletId(*b"py/tmpl1");
+	pub const MaxDeviation: Permill = Permill::from_percent(10);
+}
+
+/// Handles overflow according to the policy `IncrementPolicy` is set to.
+pub struct MockOverflowPolicy;
+
+impl OverflowPolicy for MockOverflowPolicy {
+	fn increment(value: u32, by: u32) -> Option<u32> {
+		match IncrementPolicy::get() {
+			Policy::Checked => Checked::increment(value, by),
+			Policy::Saturating => Saturating::increment(value, by),
+			Policy::Wrapping => Wrapping::increment(value, by),
+		}
+	}
 }
 
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityId = pallet_template::crypto::TemplateAuthId;
+	type MaxAuthorities = ConstU32<3>;
+	type OffchainInterval = ConstU64<5>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type SomethingDeposit = ConstU64<10>;
+	type SomethingTtl = ConstU64<10>;
+	type MaxExpiringPerBlock = ConstU32<2>;
+	type MaxScheduledPerBlock = ConstU32<2>;
+	type OverflowPolicy = MockOverflowPolicy;
+	type MaxIncrementStep = ConstU32<10>;
+	type Period = ConstU64<10>;
+	type MaxWritesPerPeriod = ConstU32<10>;
+	type MaxKeyLen = ConstU32<8>;
+	type MaxValueLen = ConstU32<16>;
+	type MaxEntries = ConstU32<3>;
+	type CommitPhaseLength = ConstU64<3>;
+	type RevealPhaseLength = ConstU64<2>;
+	type CommitDeposit = ConstU64<5>;
+	type MaxCommits = ConstU32<2>;
+	type Slashed = ();
+	type PalletId = TemplatePalletId;
+	type MaxFeeders = ConstU32<3>;
+	type MaxDeviation = MaxDeviation;
+	type HistoryDepth = ConstU32<3>;
+}
+
+impl pallet_template::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type AuthorityId = pallet_template::crypto::TemplateAuthId;
+	type MaxAuthorities = ConstU32<3>;
+	type OffchainInterval = ConstU64<5>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type SomethingDeposit = ConstU64<20>;
+	type SomethingTtl = ConstU64<20>;
+	type MaxExpiringPerBlock = ConstU32<2>;
+	type MaxScheduledPerBlock = ConstU32<2>;
+	type OverflowPolicy = MockOverflowPolicy;
+	type MaxIncrementStep = ConstU32<10>;
+	type Period = ConstU64<10>;
+	type MaxWritesPerPeriod = ConstU32<10>;
+	type MaxKeyLen = ConstU32<8>;
+	type MaxValueLen = ConstU32<16>;
+	type MaxEntries = ConstU32<3>;
+	type CommitPhaseLength = ConstU64<3>;
+	type RevealPhaseLength = ConstU64<2>;
+	type CommitDeposit = ConstU64<5>;
+	type MaxCommits = ConstU32<2>;
+	type Slashed = ();
+	type PalletId = SecondTemplatePalletId;
+	type MaxFeeders = ConstU32<3>;
+	type MaxDeviation = MaxDeviation;
+	type HistoryDepth = ConstU32<3>;
+}
+
+/// The test account with the given id.
+pub fn account(id: u8) -> AccountId {
+	sp_core::sr25519::Public::from_raw([id; 32])
+}
+
+/// Builds the test externalities, optionally seeding pallet genesis state.
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, u64)>,
+	something: Vec<(AccountId, u32)>,
+	overflow_policy: Policy,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: (1..=3).map(|id| (account(id), 100)).collect(),
+			something: Vec::new(),
+			overflow_policy: Policy::Checked,
+		}
+	}
+}
+
+impl ExtBuilder {
+	/// Free balances of the given accounts at genesis, replacing the default endowments.
+	pub fn with_balances(mut self, balances: Vec<(AccountId, u64)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	/// Values to store for the given accounts at genesis.
+	pub fn with_something(mut self, something: Vec<(AccountId, u32)>) -> Self {
+		self.something = something;
+		self
+	}
+
+	/// The overflow policy of both template instances.
+	pub fn with_overflow_policy(mut self, policy: Policy) -> Self {
+		self.overflow_policy = policy;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		IncrementPolicy::set(self.overflow_policy);
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		pallet_balances::GenesisConfig::<Test> { balances: self.balances }
+			.assimilate_storage(&mut t)
+			.unwrap();
+		pallet_template::GenesisConfig::<Test> {
+			something: self.something,
+			authorities: Vec::new(),
+			phantom: Default::default(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut ext: sp_io::TestExternalities = t.into();
+		// The offchain worker and the benchmarks sign with keys from the keystore.
+		ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+		ext
+	}
+
+	/// Build the test externalities and execute `test` in them, checking the pallet's invariants
+	/// afterwards.
+	pub fn build_and_execute(self, test: impl FnOnce()) {
+		self.build().execute_with(|| {
+			test();
+			TemplateModule::do_try_state().expect("pallet invariants hold after the test");
+			SecondTemplateModule::do_try_state().expect("pallet invariants hold after the test");
+		})
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	ExtBuilder::default().build()
 }
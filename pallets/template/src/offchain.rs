@@ -0,0 +1,127 @@
+//! Offchain worker logic for pallet-template.
+//!
+//! Every `OffchainInterval` blocks the worker fetches a single decimal `u32` over HTTP and submits
+//! it on-chain. It prefers a free unsigned transaction carrying a payload signed by one of the
+//! `Authorities`, and falls back to a signed `do_something` transaction while unsigned submissions
+//! are throttled by `NextUnsignedAt` or the node holds no authority key.
+
+use crate::{Authorities, Call, Config, NextUnsignedAt, Pallet, SomethingPayload, LOG_TARGET};
+use frame_support::traits::PalletInfoAccess;
+use frame_system::offchain::{AppCrypto, SendSignedTransaction, SendUnsignedTransaction, Signer};
+use sp_runtime::{
+	offchain::{http, Duration, StorageKind},
+	traits::IdentifyAccount,
+	RuntimeAppPublic,
+};
+use sp_std::vec::Vec;
+
+/// The URL the worker fetches values from when none is configured in offchain storage.
+pub const DEFAULT_URL: &str = "http://localhost:8000/something";
+
+/// How long to wait for an HTTP response, in milliseconds.
+const FETCH_TIMEOUT_MILLIS: u64 = 2_000;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The key in `PERSISTENT` offchain storage holding the URL the worker fetches values from.
+	///
+	/// Node operators can point the worker at another server, e.g. a local stub, by setting this
+	/// key with the `offchain_localStorageSet` RPC.
+	pub fn offchain_url_key() -> Vec<u8> {
+		[<Self as PalletInfoAccess>::name().as_bytes(), &b"::url"[..]].concat()
+	}
+
+	/// Fetch a value and submit it on-chain, unsigned if allowed at `block_number`.
+	pub(crate) fn fetch_and_submit(block_number: T::BlockNumber) -> Result<(), &'static str> {
+		let something = Self::fetch_something().map_err(|_| "failed to fetch value")?;
+
+		let authority_keys = Self::local_authority_keys();
+		if <NextUnsignedAt<T, I>>::get() <= block_number && !authority_keys.is_empty() {
+			Self::submit_unsigned(block_number, something, authority_keys)
+		} else {
+			Self::submit_signed(something)
+		}
+	}
+
+	/// The local keys of the `Authorities`, which can sign unsigned submissions.
+	fn local_authority_keys() -> Vec<T::Public> {
+		let authorities = <Authorities<T, I>>::get();
+		<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+			.into_iter()
+			.map(|key| {
+				let public: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+					key.into();
+				public.into()
+			})
+			.filter(|public: &T::Public| authorities.contains(&public.clone().into_account()))
+			.collect()
+	}
+
+	/// Fetch a value from the configured URL.
+	fn fetch_something() -> Result<u32, http::Error> {
+		let url =
+			sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &Self::offchain_url_key())
+				.unwrap_or_else(|| DEFAULT_URL.as_bytes().to_vec());
+		let url = sp_std::str::from_utf8(&url).map_err(|_| {
+			log::warn!(target: LOG_TARGET, "configured URL is not valid UTF-8");
+			http::Error::Unknown
+		})?;
+
+		let deadline =
+			sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MILLIS));
+		let pending = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+		let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+		if response.code != 200 {
+			log::warn!(target: LOG_TARGET, "unexpected status code: {}", response.code);
+			return Err(http::Error::Unknown)
+		}
+
+		let body = response.body().collect::<Vec<u8>>();
+		parse_something(&body).ok_or_else(|| {
+			log::warn!(target: LOG_TARGET, "unable to parse response body as a u32");
+			http::Error::Unknown
+		})
+	}
+
+	/// Submit `something` in a signed `do_something` transaction from any local key.
+	fn submit_signed(something: u32) -> Result<(), &'static str> {
+		let (account, result) = Signer::<T, T::AuthorityId>::any_account()
+			.send_signed_transaction(|_account| Call::<T, I>::do_something { something })
+			.ok_or("no local keys available, insert one with the `author_insertKey` RPC")?;
+		result.map_err(|()| "unable to submit signed transaction")?;
+
+		log::info!(target: LOG_TARGET, "[{:?}] submitted signed value {}", account.id, something);
+		Ok(())
+	}
+
+	/// Submit `something` in an unsigned transaction whose payload is signed by any of
+	/// `authority_keys`.
+	fn submit_unsigned(
+		block_number: T::BlockNumber,
+		something: u32,
+		authority_keys: Vec<T::Public>,
+	) -> Result<(), &'static str> {
+		let (account, result) = Signer::<T, T::AuthorityId>::any_account()
+			.with_filter(authority_keys)
+			.send_unsigned_transaction(
+				|account| SomethingPayload {
+					block_number,
+					something,
+					public: account.public.clone(),
+				},
+				|payload, signature| Call::<T, I>::submit_something_unsigned { payload, signature },
+			)
+			.ok_or("no local authority keys available")?;
+		result.map_err(|()| "unable to submit unsigned transaction")?;
+
+		log::info!(target: LOG_TARGET, "[{:?}] submitted unsigned value {}", account.id, something);
+		Ok(())
+	}
+}
+
+/// Parse a response body holding a single decimal `u32`, ignoring surrounding whitespace.
+fn parse_something(body: &[u8]) -> Option<u32> {
+	sp_std::str::from_utf8(body).ok()?.trim().parse().ok()
+}
@@ -0,0 +1,34 @@
+//! Policies for how incrementing a stored value behaves when it overflows.
+
+/// How an increment that would overflow a stored value is handled.
+pub trait OverflowPolicy {
+	/// Add `by` to `value`, or return `None` if the increment is rejected.
+	fn increment(value: u32, by: u32) -> Option<u32>;
+}
+
+/// Reject increments that overflow, failing with `StorageOverflow`.
+pub struct Checked;
+
+impl OverflowPolicy for Checked {
+	fn increment(value: u32, by: u32) -> Option<u32> {
+		value.checked_add(by)
+	}
+}
+
+/// Cap the value at `u32::MAX`.
+pub struct Saturating;
+
+impl OverflowPolicy for Saturating {
+	fn increment(value: u32, by: u32) -> Option<u32> {
+		Some(value.saturating_add(by))
+	}
+}
+
+/// Wrap the value around to zero.
+pub struct Wrapping;
+
+impl OverflowPolicy for Wrapping {
+	fn increment(value: u32, by: u32) -> Option<u32> {
+		Some(value.wrapping_add(by))
+	}
+}
@@ -1,27 +1,1505 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	crypto::TemplateAuthId, migrations, mock::*, topics, Authorities, CommitCount, Commits,
+	CurrentPhase, Deposits, Entries, EntryCount, Error, Event, ExpiresAt, Feeders, History,
+	HistoryCount, HistoryItem, KeyOf, LastExpiryBlock, NextExpiryBlock, NextUnsignedAt,
+	OracleRound, Paused, RoundPhase, Scheduled, Something, SomethingPayload, Submissions,
+	ValidateTemplateCall, ValueOf, WeightInfo, WriteCounts, WriteMode, DEFAULT_URL, PAUSED,
+	RATE_LIMITED,
+};
+use codec::Decode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{Pays, PostDispatchInfo, WithPostDispatchInfo},
+	instances::Instance1,
+	parameter_types,
+	storage::{unhashed, StoragePrefixedMap},
+	traits::{Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use frame_system::offchain::SignedPayload;
+use sp_core::{
+	offchain::{
+		testing::{OffchainState, PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+	},
+	H256,
+};
+use sp_runtime::{
+	traits::{BadOrigin, BlakeTwo256, Hash, SignedExtension, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+};
 
 #[test]
 fn it_works_for_default_value() {
-	new_test_ext().execute_with(|| {
+	ExtBuilder::default().build_and_execute(|| {
 		// Go past genesis block so events get deposited
 		System::set_block_number(1);
 		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
 		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		System::assert_last_event(Event::SomethingStored { something: 42, who: account(1) }.into());
+	});
+}
+
+#[test]
+fn values_are_stored_per_account() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 7));
+
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+		assert_eq!(TemplateModule::something(account(2)), Some(7));
 	});
 }
 
+#[test]
+fn genesis_config_seeds_values() {
+	ExtBuilder::default()
+		.with_something(vec![(account(1), 10), (account(2), 20)])
+		.build_and_execute(|| {
+			assert_eq!(TemplateModule::something(account(1)), Some(10));
+			assert_eq!(TemplateModule::something(account(2)), Some(20));
+			assert_eq!(TemplateModule::something(account(3)), None);
+
+			// Seeded values can be incremented straight away.
+			assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(account(1))));
+			assert_eq!(TemplateModule::something(account(1)), Some(11));
+		});
+}
+
 #[test]
 fn correct_error_for_none_value() {
-	new_test_ext().execute_with(|| {
+	ExtBuilder::default().build_and_execute(|| {
 		// Ensure the expected error is thrown when no value is present.
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
+			TemplateModule::cause_error(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::NoneValue.with_weight(<() as WeightInfo>::cause_error_failed())
+		);
+	});
+}
+
+#[test]
+fn cause_error_increments_own_value() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 7));
+
+		assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(account(1))));
+
+		assert_eq!(TemplateModule::something(account(1)), Some(43));
+		assert_eq!(TemplateModule::something(account(2)), Some(7));
+		// Another account without a value of its own still hits the error.
+		assert_noop!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(account(3))),
+			Error::<Test>::NoneValue.with_weight(<() as WeightInfo>::cause_error_failed())
+		);
+	});
+}
+
+#[test]
+fn cause_error_reports_overflow() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), u32::MAX));
+		assert_noop!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::StorageOverflow.with_weight(<() as WeightInfo>::cause_error_failed())
+		);
+	});
+}
+
+#[test]
+fn cause_error_saturates_with_saturating_policy() {
+	ExtBuilder::default()
+		.with_overflow_policy(Policy::Saturating)
+		.build_and_execute(|| {
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), u32::MAX));
+			assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(account(1))));
+			assert_eq!(TemplateModule::something(account(1)), Some(u32::MAX));
+		});
+}
+
+#[test]
+fn cause_error_wraps_with_wrapping_policy() {
+	ExtBuilder::default()
+		.with_overflow_policy(Policy::Wrapping)
+		.build_and_execute(|| {
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), u32::MAX));
+			assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(account(1))));
+			assert_eq!(TemplateModule::something(account(1)), Some(0));
+		});
+}
+
+#[test]
+fn increment_by_adds_to_own_value() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 7));
+
+		assert_ok!(TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 10));
+
+		assert_eq!(TemplateModule::something(account(1)), Some(52));
+		assert_eq!(TemplateModule::something(account(2)), Some(7));
+		System::assert_last_event(Event::SomethingStored { something: 52, who: account(1) }.into());
+	});
+}
+
+#[test]
+fn increment_by_rejects_steps_above_the_maximum() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_noop!(
+			TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 11),
+			Error::<Test>::IncrementTooLarge
+		);
+	});
+}
+
+#[test]
+fn increment_by_requires_a_value() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 1),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn increment_by_fails_on_overflow_with_checked_policy() {
+	ExtBuilder::default()
+		.with_overflow_policy(Policy::Checked)
+		.build_and_execute(|| {
+			assert_ok!(TemplateModule::do_something(
+				RuntimeOrigin::signed(account(1)),
+				u32::MAX - 5
+			));
+			assert_noop!(
+				TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 10),
+				Error::<Test>::StorageOverflow
+			);
+		});
+}
+
+#[test]
+fn increment_by_saturates_with_saturating_policy() {
+	ExtBuilder::default()
+		.with_overflow_policy(Policy::Saturating)
+		.build_and_execute(|| {
+			assert_ok!(TemplateModule::do_something(
+				RuntimeOrigin::signed(account(1)),
+				u32::MAX - 5
+			));
+			assert_ok!(TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 10));
+			assert_eq!(TemplateModule::something(account(1)), Some(u32::MAX));
+		});
+}
+
+#[test]
+fn increment_by_wraps_with_wrapping_policy() {
+	ExtBuilder::default()
+		.with_overflow_policy(Policy::Wrapping)
+		.build_and_execute(|| {
+			assert_ok!(TemplateModule::do_something(
+				RuntimeOrigin::signed(account(1)),
+				u32::MAX - 5
+			));
+			assert_ok!(TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 10));
+			assert_eq!(TemplateModule::something(account(1)), Some(4));
+		});
+}
+
+#[test]
+fn storing_a_new_value_is_free() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(
+			TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42),
+			Ok(PostDispatchInfo {
+				actual_weight: Some(<() as WeightInfo>::do_something_insert()),
+				pays_fee: Pays::No,
+			})
+		);
+		assert_eq!(
+			TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 7),
+			Ok(PostDispatchInfo {
+				actual_weight: Some(<() as WeightInfo>::do_something_update()),
+				pays_fee: Pays::Yes,
+			})
+		);
+	});
+}
+
+#[test]
+fn cause_error_charges_actual_weight() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_eq!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(account(1))),
+			Ok(Some(<() as WeightInfo>::cause_error()).into())
+		);
+		assert!(<() as WeightInfo>::cause_error_failed().all_lte(<() as WeightInfo>::cause_error()));
+	});
+}
+
+#[test]
+fn clear_something_removes_own_value() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 7));
+
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(account(1))));
+
+		assert_eq!(TemplateModule::something(account(1)), None);
+		assert_eq!(TemplateModule::something(account(2)), Some(7));
+		System::assert_last_event(Event::SomethingCleared { who: account(1) }.into());
+		// Nothing left to clear.
+		assert_noop!(
+			TemplateModule::clear_something(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn admin_can_set_and_clear_any_value() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+
+		assert_ok!(TemplateModule::force_set_something(RuntimeOrigin::root(), account(1), 7));
+		assert_eq!(TemplateModule::something(account(1)), Some(7));
+		System::assert_last_event(Event::SomethingStored { something: 7, who: account(1) }.into());
+
+		assert_ok!(TemplateModule::force_clear_something(RuntimeOrigin::root(), account(1)));
+		assert_eq!(TemplateModule::something(account(1)), None);
+		System::assert_last_event(Event::SomethingCleared { who: account(1) }.into());
+		assert_noop!(
+			TemplateModule::force_clear_something(RuntimeOrigin::root(), account(1)),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn signed_origins_cannot_use_admin_calls() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 42));
+
+		assert_noop!(
+			TemplateModule::force_set_something(RuntimeOrigin::signed(account(1)), account(2), 7),
+			BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::force_clear_something(RuntimeOrigin::signed(account(1)), account(2)),
+			BadOrigin
+		);
+		// Root has no account to use the signed path with.
+		assert_noop!(TemplateModule::do_something(RuntimeOrigin::root(), 7), BadOrigin);
+		assert_eq!(TemplateModule::something(account(2)), Some(42));
+	});
+}
+
+#[test]
+fn storing_a_value_reserves_a_deposit_until_cleared() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_eq!(Balances::reserved_balance(account(1)), 10);
+		assert_eq!(Deposits::<Test>::get(account(1)), Some(10));
+
+		// Updating the value does not reserve another deposit.
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 7));
+		assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(account(1))));
+		assert_eq!(Balances::reserved_balance(account(1)), 10);
+
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(account(1))));
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_eq!(Balances::free_balance(account(1)), 100);
+		assert_eq!(Deposits::<Test>::get(account(1)), None);
+	});
+}
+
+#[test]
+fn storing_a_value_requires_the_deposit() {
+	ExtBuilder::default()
+		.with_balances(vec![(account(1), 5)])
+		.build_and_execute(|| {
+			assert_noop!(
+				TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42),
+				Error::<Test>::InsufficientBalance
+			);
+			assert_eq!(TemplateModule::something(account(1)), None);
+		});
+}
+
+#[test]
+fn admin_calls_take_no_deposit_but_release_held_ones() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(TemplateModule::force_set_something(RuntimeOrigin::root(), account(1), 7));
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_eq!(Deposits::<Test>::get(account(1)), None);
+		// The account's own first write still counts as storing a new entry.
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 42));
+		assert_eq!(Balances::reserved_balance(account(2)), 10);
+
+		assert_ok!(TemplateModule::force_clear_something(RuntimeOrigin::root(), account(2)));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_ok!(TemplateModule::force_clear_something(RuntimeOrigin::root(), account(1)));
+		assert_eq!(Balances::free_balance(account(1)), 100);
+	});
+}
+
+/// Run `on_idle` for `now` with all the weight it wants.
+fn run_on_idle(now: u64) -> Weight {
+	System::set_block_number(now);
+	TemplateModule::on_idle(now, Weight::MAX)
+}
+
+#[test]
+fn values_expire_after_their_ttl() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_eq!(ExpiresAt::<Test>::get(account(1)), Some(11));
+		run_on_idle(1);
+
+		run_on_idle(10);
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+
+		run_on_idle(11);
+		assert_eq!(TemplateModule::something(account(1)), None);
+		assert_eq!(ExpiresAt::<Test>::get(account(1)), None);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		System::assert_last_event(Event::Expired { who: account(1) }.into());
+		assert_eq!(NextExpiryBlock::<Test>::get(), 12);
+	});
+}
+
+#[test]
+fn rewriting_a_value_extends_its_ttl() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		run_on_idle(1);
+		System::set_block_number(5);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 7));
+
+		run_on_idle(11);
+		assert_eq!(TemplateModule::something(account(1)), Some(7));
+		run_on_idle(15);
+		assert_eq!(TemplateModule::something(account(1)), None);
+	});
+}
+
+#[test]
+fn cleared_values_are_skipped_when_purging() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(account(1))));
+		run_on_idle(1);
+
+		run_on_idle(11);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TemplateModule(Event::Expired { .. })
+		)));
+	});
+}
+
+#[test]
+fn values_queued_before_on_idle_first_runs_still_expire() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_eq!(NextExpiryBlock::<Test>::get(), 1);
+
+		// `on_idle` gets no weight until long after the value expired.
+		assert_eq!(TemplateModule::on_idle(11, Weight::zero()), Weight::zero());
+		System::set_block_number(20);
+		run_on_idle(20);
+
+		assert_eq!(TemplateModule::something(account(1)), None);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_eq!(NextExpiryBlock::<Test>::get(), 21);
+	});
+}
+
+#[test]
+fn genesis_values_never_expire() {
+	ExtBuilder::default()
+		.with_something(vec![(account(1), 10)])
+		.build_and_execute(|| {
+			run_on_idle(1);
+			run_on_idle(100);
+			assert_eq!(TemplateModule::something(account(1)), Some(10));
+		});
+}
+
+#[test]
+fn on_idle_only_purges_within_the_remaining_weight() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		run_on_idle(1);
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 7));
+
+		// Not even enough weight to look at the cursor.
+		assert_eq!(TemplateModule::on_idle(12, Weight::zero()), Weight::zero());
+		assert_eq!(NextExpiryBlock::<Test>::get(), 2);
+
+		// Enough weight for a single full expiry queue.
+		let weight = <() as WeightInfo>::on_idle_base() +
+			<() as WeightInfo>::purge_expired(<Test as crate::Config>::MaxExpiringPerBlock::get());
+		System::set_block_number(12);
+		assert!(TemplateModule::on_idle(12, weight).all_lte(weight));
+		assert_eq!(NextExpiryBlock::<Test>::get(), 3);
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+
+		// The remaining blocks are caught up on later.
+		run_on_idle(13);
+		assert_eq!(TemplateModule::something(account(1)), None);
+		assert_eq!(TemplateModule::something(account(2)), None);
+		assert_eq!(NextExpiryBlock::<Test>::get(), 14);
+	});
+}
+
+#[test]
+fn full_expiry_queues_spill_into_the_next_block() {
+	let balances = (1..=4).map(|id| (account(id), 100)).collect();
+	ExtBuilder::default().with_balances(balances).build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 1));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 2));
+		// Rewriting in the same block does not take another slot.
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 3));
+
+		assert_eq!(ExpiresAt::<Test>::get(account(2)), Some(11));
+
+		// Another account can still store a value once the queue is full: it expires a block
+		// later instead.
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(3)), 4));
+		assert_eq!(ExpiresAt::<Test>::get(account(3)), Some(12));
+		assert_eq!(LastExpiryBlock::<Test>::get(), 12);
+		// Later values queue behind it.
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(4)), 5));
+		assert_eq!(ExpiresAt::<Test>::get(account(4)), Some(12));
+
+		run_on_idle(11);
+		assert_eq!(TemplateModule::something(account(2)), None);
+		assert_eq!(TemplateModule::something(account(3)), Some(4));
+		run_on_idle(12);
+		assert_eq!(TemplateModule::something(account(3)), None);
+		assert_eq!(TemplateModule::something(account(4)), None);
+	});
+}
+
+/// Advance to block `n`, running `on_initialize` for every block on the way.
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		TemplateModule::on_initialize(next);
+	}
+}
+
+#[test]
+fn scheduled_updates_are_applied_at_their_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 42, 3));
+		System::assert_last_event(
+			Event::SomethingScheduled { something: 42, who: account(1), at: 3 }.into(),
+		);
+
+		run_to_block(2);
+		assert_eq!(TemplateModule::something(account(1)), None);
+
+		run_to_block(3);
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+		assert_eq!(Balances::reserved_balance(account(1)), 10);
+		assert!(!Scheduled::<Test>::contains_key(3));
+		System::assert_last_event(
+			Event::ScheduledApplied { something: 42, who: account(1) }.into(),
+		);
+	});
+}
+
+#[test]
+fn scheduling_is_validated() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 42, 5),
+			Error::<Test>::ScheduleInPast
+		);
+
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 1, 6));
+		assert_noop!(
+			TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 2, 6),
+			Error::<Test>::AlreadyScheduled
+		);
+		// Another block is fine.
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 2, 7));
+
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(2)), 3, 6));
+		assert_noop!(
+			TemplateModule::schedule_something(RuntimeOrigin::signed(account(3)), 4, 6),
+			Error::<Test>::TooManyScheduled
+		);
+	});
+}
+
+#[test]
+fn scheduled_updates_can_be_cancelled() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 42, 3));
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(2)), 7, 3));
+
+		assert_ok!(TemplateModule::cancel_scheduled(RuntimeOrigin::signed(account(1)), 3));
+		System::assert_last_event(Event::ScheduledCancelled { who: account(1), at: 3 }.into());
+		assert_noop!(
+			TemplateModule::cancel_scheduled(RuntimeOrigin::signed(account(1)), 3),
+			Error::<Test>::NotScheduled
+		);
+		assert_noop!(
+			TemplateModule::cancel_scheduled(RuntimeOrigin::signed(account(1)), 4),
+			Error::<Test>::NotScheduled
+		);
+
+		assert_ok!(TemplateModule::cancel_scheduled(RuntimeOrigin::signed(account(2)), 3));
+		// The last cancellation removes the block's queue altogether.
+		assert!(!Scheduled::<Test>::contains_key(3));
+
+		run_to_block(3);
+		assert_eq!(TemplateModule::something(account(1)), None);
+		assert_eq!(TemplateModule::something(account(2)), None);
+	});
+}
+
+#[test]
+fn failed_scheduled_updates_do_not_affect_others() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		// Account 4 cannot afford the deposit.
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(4)), 42, 2));
+		assert_ok!(TemplateModule::schedule_something(RuntimeOrigin::signed(account(1)), 7, 2));
+
+		run_to_block(2);
+		assert_eq!(TemplateModule::something(account(4)), None);
+		assert_eq!(TemplateModule::something(account(1)), Some(7));
+		System::assert_has_event(
+			Event::ScheduledFailed {
+				who: account(4),
+				error: Error::<Test>::InsufficientBalance.into(),
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::ScheduledApplied { something: 7, who: account(1) }.into());
+	});
+}
+
+#[test]
+fn instances_are_independent() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(SecondTemplateModule::do_something(RuntimeOrigin::signed(account(1)), 7));
+		System::assert_last_event(
+			Event::<Test, Instance1>::SomethingStored { something: 7, who: account(1) }.into(),
+		);
+
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+		assert_eq!(SecondTemplateModule::something(account(1)), Some(7));
+		// Each instance reserves its own deposit and expires values after its own TTL.
+		assert_eq!(Balances::reserved_balance(account(1)), 30);
+		assert_eq!(ExpiresAt::<Test>::get(account(1)), Some(11));
+		assert_eq!(ExpiresAt::<Test, Instance1>::get(account(1)), Some(21));
+
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(account(1))));
+		assert_eq!(TemplateModule::something(account(1)), None);
+		assert_eq!(SecondTemplateModule::something(account(1)), Some(7));
+		assert_eq!(Balances::reserved_balance(account(1)), 20);
+	});
+}
+
+#[test]
+fn try_state_detects_inconsistent_storage() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::do_try_state());
+
+		Deposits::<Test>::insert(account(1), 10);
+		assert_eq!(TemplateModule::do_try_state(), Err("deposit held without a value"));
+		Something::<Test>::insert(account(1), 42);
+		assert_eq!(TemplateModule::do_try_state(), Err("deposit exceeds the reserved balance"));
+		Deposits::<Test>::remove(account(1));
+
+		ExpiresAt::<Test>::insert(account(1), 11);
+		assert_eq!(TemplateModule::do_try_state(), Err("value not queued at its expiry"));
+		ExpiresAt::<Test>::remove(account(1));
+
+		NextUnsignedAt::<Test>::put(100);
+		assert_eq!(
+			TemplateModule::do_try_state(),
+			Err("unsigned submissions throttled for more than an interval")
+		);
+	});
+}
+
+parameter_types! {
+	pub MigrationOwner: Option<AccountId> = Some(account(7));
+	pub const NoMigrationOwner: Option<AccountId> = None;
+}
+
+#[test]
+fn migration_to_v1_moves_global_value_to_owner() {
+	ExtBuilder::default().build_and_execute(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		// The old global value lived at the prefix now used by the map.
+		unhashed::put(&Something::<Test>::final_prefix(), &42u32);
+
+		migrations::v1::MigrateToV1::<Test, MigrationOwner>::on_runtime_upgrade();
+
+		assert_eq!(unhashed::get::<u32>(&Something::<Test>::final_prefix()), None);
+		assert_eq!(TemplateModule::something(account(7)), Some(42));
+		assert_eq!(Something::<Test>::iter().count(), 1);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn migration_to_v1_drops_value_without_owner() {
+	ExtBuilder::default().build_and_execute(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		unhashed::put(&Something::<Test>::final_prefix(), &42u32);
+
+		migrations::v1::MigrateToV1::<Test, NoMigrationOwner>::on_runtime_upgrade();
+
+		assert_eq!(unhashed::get::<u32>(&Something::<Test>::final_prefix()), None);
+		assert_eq!(Something::<Test>::iter().count(), 0);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn migration_to_v1_is_skipped_on_newer_versions() {
+	ExtBuilder::default().build_and_execute(|| {
+		StorageVersion::new(1).put::<TemplateModule>();
+		unhashed::put(&Something::<Test>::final_prefix(), &42u32);
+
+		migrations::v1::MigrateToV1::<Test, MigrationOwner>::on_runtime_upgrade();
+
+		// Nothing was touched.
+		assert_eq!(unhashed::get::<u32>(&Something::<Test>::final_prefix()), Some(42));
+		assert_eq!(TemplateModule::something(account(7)), None);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn migration_to_v1_passes_try_runtime_checks() {
+	ExtBuilder::default().build_and_execute(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		unhashed::put(&Something::<Test>::final_prefix(), &42u32);
+
+		assert_ok!(migrations::v1::MigrateToV1::<Test, MigrationOwner>::try_on_runtime_upgrade(
+			true
+		));
+		assert_eq!(TemplateModule::something(account(7)), Some(42));
+	});
+}
+
+/// Answer the offchain worker's next HTTP request to `uri` with `response`.
+fn expect_fetch(state: &mut OffchainState, uri: &str, response: &[u8]) {
+	state.expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: uri.into(),
+		response: Some(response.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+/// Generate an offchain worker key in the test keystore.
+fn generate_worker_key() -> AccountId {
+	sp_io::crypto::sr25519_generate(crate::KEY_TYPE, None)
+}
+
+/// Generate an offchain worker key in the test keystore and make its account an authority.
+fn generate_authority_key() -> AccountId {
+	let public = generate_worker_key();
+	Authorities::<Test>::mutate(|authorities| authorities.try_push(public).unwrap());
+	public
+}
+
+#[test]
+fn offchain_worker_submits_unsigned_transaction_with_signed_payload() {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	expect_fetch(&mut offchain_state.write(), DEFAULT_URL, b"42\n");
+
+	ext.execute_with(|| {
+		let public = generate_authority_key();
+		System::set_block_number(5);
+
+		TemplateModule::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		match tx.call {
+			RuntimeCall::TemplateModule(crate::Call::submit_something_unsigned {
+				payload,
+				signature,
+			}) => {
+				assert_eq!(payload, SomethingPayload { block_number: 5, something: 42, public });
+				assert!(SignedPayload::<Test>::verify::<TemplateAuthId>(&payload, signature));
+			},
+			call => panic!("unexpected call: {:?}", call),
+		}
+	});
+}
+
+#[test]
+fn offchain_worker_falls_back_to_signed_transaction() {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	// The worker is pointed at a local stub server.
+	expect_fetch(&mut offchain_state.write(), "http://127.0.0.1:9933/value", b"7");
+
+	ext.execute_with(|| {
+		generate_worker_key();
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			&TemplateModule::offchain_url_key(),
+			b"http://127.0.0.1:9933/value",
+		);
+		System::set_block_number(5);
+		// Unsigned submissions are throttled until block 10.
+		NextUnsignedAt::<Test>::put(10);
+
+		TemplateModule::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature.unwrap().0, 0);
+		assert_eq!(
+			tx.call,
+			RuntimeCall::TemplateModule(crate::Call::do_something { something: 7 })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_needs_an_authority_key_to_submit_unsigned() {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	expect_fetch(&mut offchain_state.write(), DEFAULT_URL, b"42");
+
+	ext.execute_with(|| {
+		generate_worker_key();
+		System::set_block_number(5);
+
+		TemplateModule::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(tx.signature.is_some());
+		assert_eq!(
+			tx.call,
+			RuntimeCall::TemplateModule(crate::Call::do_something { something: 42 })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_only_runs_every_interval() {
+	let (offchain, _offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		generate_worker_key();
+		System::set_block_number(3);
+
+		// No request is expected, so fetching would panic.
+		TemplateModule::offchain_worker(3);
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn unsigned_submissions_are_validated_and_throttled() {
+	ExtBuilder::default().build_and_execute(|| {
+		let public = generate_authority_key();
+		System::set_block_number(5);
+		let validate = |call: &crate::Call<Test>| {
+			TemplateModule::validate_unsigned(TransactionSource::External, call)
+		};
+
+		let payload = SomethingPayload { block_number: 5, something: 42, public };
+		let signature = SignedPayload::<Test>::sign::<TemplateAuthId>(&payload).unwrap();
+		let call = crate::Call::submit_something_unsigned {
+			payload: payload.clone(),
+			signature: signature.clone(),
+		};
+		assert_ok!(validate(&call));
+
+		// The signature must match the payload.
+		let forged = crate::Call::submit_something_unsigned {
+			payload: SomethingPayload { something: 43, ..payload.clone() },
+			signature: signature.clone(),
+		};
+		assert_eq!(validate(&forged), InvalidTransaction::BadProof.into());
+
+		// Payloads from the future are rejected.
+		let future = SomethingPayload { block_number: 6, ..payload.clone() };
+		let future = crate::Call::submit_something_unsigned {
+			signature: SignedPayload::<Test>::sign::<TemplateAuthId>(&future).unwrap(),
+			payload: future,
+		};
+		assert_eq!(validate(&future), InvalidTransaction::Future.into());
+
+		// Only authorities can sign payloads.
+		let outsider = SomethingPayload { public: generate_worker_key(), ..payload.clone() };
+		let outsider_signature = SignedPayload::<Test>::sign::<TemplateAuthId>(&outsider).unwrap();
+		let call_from_outsider = crate::Call::submit_something_unsigned {
+			payload: outsider.clone(),
+			signature: outsider_signature.clone(),
+		};
+		assert_eq!(validate(&call_from_outsider), InvalidTransaction::BadSigner.into());
+		assert_noop!(
+			TemplateModule::submit_something_unsigned(
+				RuntimeOrigin::none(),
+				outsider,
+				outsider_signature
+			),
+			Error::<Test>::NotAuthority
+		);
+
+		assert_ok!(TemplateModule::submit_something_unsigned(
+			RuntimeOrigin::none(),
+			payload,
+			signature
+		));
+		assert_eq!(TemplateModule::something(public), Some(42));
+		System::assert_last_event(Event::SomethingStored { something: 42, who: public }.into());
+
+		// Further submissions are throttled for an interval.
+		assert_eq!(NextUnsignedAt::<Test>::get(), 10);
+		assert_eq!(validate(&call), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn admin_origin_manages_authorities() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::add_authority(RuntimeOrigin::signed(account(1)), account(1)),
+			BadOrigin
+		);
+
+		for id in 1..=3 {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), account(id)));
+		}
+		System::assert_last_event(Event::AuthorityAdded { who: account(3) }.into());
+		assert_noop!(
+			TemplateModule::add_authority(RuntimeOrigin::root(), account(1)),
+			Error::<Test>::AlreadyAuthority
+		);
+		assert_noop!(
+			TemplateModule::add_authority(RuntimeOrigin::root(), account(4)),
+			Error::<Test>::TooManyAuthorities
+		);
+
+		assert_ok!(TemplateModule::remove_authority(RuntimeOrigin::root(), account(2)));
+		System::assert_last_event(Event::AuthorityRemoved { who: account(2) }.into());
+		assert_eq!(Authorities::<Test>::get().into_inner(), vec![account(1), account(3)]);
+		assert_noop!(
+			TemplateModule::remove_authority(RuntimeOrigin::root(), account(2)),
+			Error::<Test>::NotAuthority
+		);
+		// The instances have separate authorities.
+		assert!(Authorities::<Test, Instance1>::get().is_empty());
+	});
+}
+
+#[test]
+fn writes_are_rate_limited_per_period() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		for value in 0..10 {
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), value));
+		}
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 10),
+			Error::<Test>::RateLimited
+		);
+		assert_noop!(
+			TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 1),
+			Error::<Test>::RateLimited
+		);
+		assert_noop!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::RateLimited.with_weight(<() as WeightInfo>::cause_error_failed())
+		);
+		// Other accounts have limits of their own.
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(2)), 10));
+		// Clearing a value is not limited.
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(account(1))));
+
+		// The limit resets in the next period.
+		System::set_block_number(10);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 10));
+		assert_eq!(WriteCounts::<Test>::get(account(1)), (10, 1));
+	});
+}
+
+#[test]
+fn failed_writes_do_not_count() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			TemplateModule::increment_by(RuntimeOrigin::signed(account(1)), 1),
 			Error::<Test>::NoneValue
 		);
+		assert_eq!(WriteCounts::<Test>::get(account(1)), (0, 0));
+	});
+}
+
+#[test]
+fn rate_limited_calls_are_kept_out_of_the_pool() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let validate = |call: RuntimeCall| {
+			ValidateTemplateCall::<Test>::new().validate(&account(1), &call, &Default::default(), 0)
+		};
+		let write = RuntimeCall::TemplateModule(crate::Call::do_something { something: 42 });
+		let clear = RuntimeCall::TemplateModule(crate::Call::clear_something {});
+
+		for value in 0..10 {
+			assert_ok!(validate(write.clone()));
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), value));
+		}
+		assert_eq!(validate(write.clone()), InvalidTransaction::Custom(RATE_LIMITED).into());
+		assert_ok!(validate(clear));
+		// Only the limits of the extension's own instance apply.
+		let second_write =
+			RuntimeCall::SecondTemplateModule(crate::Call::do_something { something: 42 });
+		assert_ok!(validate(second_write.clone()));
+		assert_ok!(ValidateTemplateCall::<Test, Instance1>::new().validate(
+			&account(1),
+			&second_write,
+			&Default::default(),
+			0
+		));
+	});
+}
+
+fn key(key: &[u8]) -> KeyOf<Test> {
+	key.to_vec().try_into().unwrap()
+}
+
+fn value(value: &[u8]) -> ValueOf<Test> {
+	value.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn set_entry_stores_and_overwrites_entries() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_entry(
+			RuntimeOrigin::signed(account(1)),
+			key(b"name"),
+			value(b"alice")
+		));
+		System::assert_last_event(
+			Event::EntrySet { who: account(1), key: key(b"name"), value: value(b"alice") }.into(),
+		);
+		assert_ok!(TemplateModule::set_entry(
+			RuntimeOrigin::signed(account(1)),
+			key(b"name"),
+			value(b"bob")
+		));
+
+		assert_eq!(Entries::<Test>::get(account(1), key(b"name")), Some(value(b"bob")));
+		assert_eq!(Entries::<Test>::get(account(2), key(b"name")), None);
+		// Overwriting an entry does not count towards the limit.
+		assert_eq!(EntryCount::<Test>::get(account(1)), 1);
+	});
+}
+
+#[test]
+fn set_entry_respects_max_entries() {
+	ExtBuilder::default().build_and_execute(|| {
+		for k in [b"a", b"b", b"c"] {
+			assert_ok!(TemplateModule::set_entry(
+				RuntimeOrigin::signed(account(1)),
+				key(k),
+				value(b"")
+			));
+		}
+		assert_noop!(
+			TemplateModule::set_entry(RuntimeOrigin::signed(account(1)), key(b"d"), value(b"")),
+			Error::<Test>::TooManyEntries
+		);
+		// Existing entries can still be updated.
+		assert_ok!(TemplateModule::set_entry(
+			RuntimeOrigin::signed(account(1)),
+			key(b"a"),
+			value(b"1")
+		));
+	});
+}
+
+#[test]
+fn remove_entry_removes_a_single_entry() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		for k in [b"a", b"b"] {
+			assert_ok!(TemplateModule::set_entry(
+				RuntimeOrigin::signed(account(1)),
+				key(k),
+				value(b"")
+			));
+		}
+
+		assert_ok!(TemplateModule::remove_entry(RuntimeOrigin::signed(account(1)), key(b"a")));
+		System::assert_last_event(Event::EntryRemoved { who: account(1), key: key(b"a") }.into());
+		assert!(!Entries::<Test>::contains_key(account(1), key(b"a")));
+		assert!(Entries::<Test>::contains_key(account(1), key(b"b")));
+		assert_eq!(EntryCount::<Test>::get(account(1)), 1);
+
+		assert_noop!(
+			TemplateModule::remove_entry(RuntimeOrigin::signed(account(1)), key(b"a")),
+			Error::<Test>::NoEntry
+		);
+		assert_ok!(TemplateModule::remove_entry(RuntimeOrigin::signed(account(1)), key(b"b")));
+		assert!(!EntryCount::<Test>::contains_key(account(1)));
+	});
+}
+
+#[test]
+fn clear_all_removes_every_entry_of_the_caller() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		for k in [b"a", b"b"] {
+			assert_ok!(TemplateModule::set_entry(
+				RuntimeOrigin::signed(account(1)),
+				key(k),
+				value(b"")
+			));
+		}
+		assert_ok!(TemplateModule::set_entry(
+			RuntimeOrigin::signed(account(2)),
+			key(b"a"),
+			value(b"")
+		));
+
+		assert_eq!(
+			TemplateModule::clear_all(RuntimeOrigin::signed(account(1))),
+			Ok(Some(<() as WeightInfo>::clear_all(2)).into())
+		);
+		System::assert_last_event(Event::EntriesCleared { who: account(1), count: 2 }.into());
+		assert_eq!(Entries::<Test>::iter_prefix(account(1)).count(), 0);
+		assert!(!EntryCount::<Test>::contains_key(account(1)));
+		assert!(Entries::<Test>::contains_key(account(2), key(b"a")));
+
+		assert_noop!(
+			TemplateModule::clear_all(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::NoEntry
+		);
+	});
+}
+
+const SALT: [u8; 32] = [7; 32];
+
+#[test]
+fn commit_reveal_rounds_alternate_phases() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(CurrentPhase::<Test>::get(), None);
+
+		run_to_block(1);
+		assert_eq!(CurrentPhase::<Test>::get(), Some((RoundPhase::Commit, 4)));
+		System::assert_last_event(
+			Event::PhaseStarted { phase: RoundPhase::Commit, ends_at: 4 }.into(),
+		);
+
+		run_to_block(4);
+		assert_eq!(CurrentPhase::<Test>::get(), Some((RoundPhase::Reveal, 6)));
+
+		run_to_block(6);
+		assert_eq!(CurrentPhase::<Test>::get(), Some((RoundPhase::Commit, 9)));
+	});
+}
+
+#[test]
+fn committed_values_are_stored_when_revealed() {
+	ExtBuilder::default().build_and_execute(|| {
+		run_to_block(1);
+		let hash = TemplateModule::commitment(&account(1), 42, &SALT);
+		assert_ok!(TemplateModule::commit(RuntimeOrigin::signed(account(1)), hash));
+		System::assert_last_event(Event::Committed { who: account(1), hash }.into());
+		assert_eq!(Balances::reserved_balance(account(1)), 5);
+		// Values cannot be revealed before the reveal phase.
+		assert_noop!(
+			TemplateModule::reveal(RuntimeOrigin::signed(account(1)), 42, SALT),
+			Error::<Test>::NotRevealPhase
+		);
+
+		run_to_block(4);
+		assert_noop!(
+			TemplateModule::commit(RuntimeOrigin::signed(account(2)), hash),
+			Error::<Test>::NotCommitPhase
+		);
+		assert_ok!(TemplateModule::reveal(RuntimeOrigin::signed(account(1)), 42, SALT));
+		System::assert_last_event(Event::Revealed { who: account(1), something: 42 }.into());
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+		// The commitment's deposit is released and the value's deposit reserved.
+		assert_eq!(Balances::reserved_balance(account(1)), 10);
+		assert!(!Commits::<Test>::contains_key(account(1)));
+		assert_noop!(
+			TemplateModule::reveal(RuntimeOrigin::signed(account(1)), 42, SALT),
+			Error::<Test>::NoCommitment
+		);
+	});
+}
+
+#[test]
+fn reveals_must_match_the_commitment() {
+	ExtBuilder::default().build_and_execute(|| {
+		run_to_block(1);
+		let hash = TemplateModule::commitment(&account(1), 42, &SALT);
+		assert_ok!(TemplateModule::commit(RuntimeOrigin::signed(account(1)), hash));
+		// Copying another account's commitment does not allow revealing its value.
+		assert_ok!(TemplateModule::commit(RuntimeOrigin::signed(account(2)), hash));
+
+		run_to_block(4);
+		assert_noop!(
+			TemplateModule::reveal(RuntimeOrigin::signed(account(1)), 43, SALT),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(
+			TemplateModule::reveal(RuntimeOrigin::signed(account(1)), 42, [0; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(
+			TemplateModule::reveal(RuntimeOrigin::signed(account(2)), 42, SALT),
+			Error::<Test>::InvalidReveal
+		);
+	});
+}
+
+#[test]
+fn commits_are_limited_per_round() {
+	ExtBuilder::default().build_and_execute(|| {
+		run_to_block(1);
+		let hash = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::commit(RuntimeOrigin::signed(account(1)), hash));
+		assert_noop!(
+			TemplateModule::commit(RuntimeOrigin::signed(account(1)), hash),
+			Error::<Test>::AlreadyCommitted
+		);
+		assert_ok!(TemplateModule::commit(RuntimeOrigin::signed(account(2)), hash));
+		assert_noop!(
+			TemplateModule::commit(RuntimeOrigin::signed(account(3)), hash),
+			Error::<Test>::TooManyCommits
+		);
+	});
+}
+
+#[test]
+fn unrevealed_commitments_are_slashed() {
+	ExtBuilder::default().build_and_execute(|| {
+		run_to_block(1);
+		let hash = TemplateModule::commitment(&account(1), 42, &SALT);
+		assert_ok!(TemplateModule::commit(RuntimeOrigin::signed(account(1)), hash));
+		assert_ok!(TemplateModule::commit(
+			RuntimeOrigin::signed(account(2)),
+			TemplateModule::commitment(&account(2), 7, &SALT)
+		));
+
+		run_to_block(4);
+		assert_ok!(TemplateModule::reveal(RuntimeOrigin::signed(account(1)), 42, SALT));
+
+		run_to_block(6);
+		System::assert_has_event(Event::CommitSlashed { who: account(2), amount: 5 }.into());
+		assert_eq!(Balances::free_balance(account(2)), 95);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(CommitCount::<Test>::get(), 0);
+		// The revealed value is kept.
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+	});
+}
+
+/// Enable oracle mode with accounts 1 to 3 as feeders.
+fn setup_oracle() {
+	assert_ok!(TemplateModule::set_mode(RuntimeOrigin::root(), WriteMode::Oracle));
+	for id in 1..=3 {
+		assert_ok!(TemplateModule::add_feeder(RuntimeOrigin::root(), account(id)));
+	}
+}
+
+#[test]
+fn feeders_are_managed_by_admin_origin() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::add_feeder(RuntimeOrigin::signed(account(1)), account(1)),
+			BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_mode(RuntimeOrigin::signed(account(1)), WriteMode::Oracle),
+			BadOrigin
+		);
+
+		assert_ok!(TemplateModule::add_feeder(RuntimeOrigin::root(), account(1)));
+		System::assert_last_event(Event::FeederAdded { who: account(1) }.into());
+		assert_noop!(
+			TemplateModule::add_feeder(RuntimeOrigin::root(), account(1)),
+			Error::<Test>::AlreadyFeeder
+		);
+		for id in 2..=3 {
+			assert_ok!(TemplateModule::add_feeder(RuntimeOrigin::root(), account(id)));
+		}
+		assert_noop!(
+			TemplateModule::add_feeder(RuntimeOrigin::root(), account(4)),
+			Error::<Test>::TooManyFeeders
+		);
+
+		assert_ok!(TemplateModule::remove_feeder(RuntimeOrigin::root(), account(2)));
+		System::assert_last_event(Event::FeederRemoved { who: account(2) }.into());
+		assert_eq!(Feeders::<Test>::get().into_inner(), vec![account(1), account(3)]);
+		assert_noop!(
+			TemplateModule::remove_feeder(RuntimeOrigin::root(), account(2)),
+			Error::<Test>::NotFeeder
+		);
+	});
+}
+
+#[test]
+fn oracle_mode_stores_the_median_at_the_end_of_the_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		setup_oracle();
+		for (id, value) in [(1, 100), (2, 102), (3, 101)] {
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(id)), value));
+		}
+		System::assert_last_event(Event::ValueSubmitted { something: 101, who: account(3) }.into());
+		// Submissions are not stored for the feeders themselves.
+		assert_eq!(TemplateModule::something(account(1)), None);
+
+		TemplateModule::on_finalize(1);
+		let oracle = TemplateModule::oracle_account();
+		assert_eq!(TemplateModule::something(&oracle), Some(101));
+		System::assert_last_event(
+			Event::RoundFinalized { round: 0, something: 101, submissions: 3, outliers: 0 }.into(),
+		);
+		assert!(!Submissions::<Test>::exists());
+
+		// Rounds without submissions leave the value alone.
+		System::set_block_number(2);
+		TemplateModule::on_finalize(2);
+		assert_eq!(TemplateModule::something(&oracle), Some(101));
+		assert_eq!(OracleRound::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn oracle_submissions_are_validated() {
+	ExtBuilder::default().build_and_execute(|| {
+		setup_oracle();
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(account(4)), 100),
+			Error::<Test>::NotFeeder
+		);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 100));
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 101),
+			Error::<Test>::AlreadySubmitted
+		);
+	});
+}
+
+#[test]
+fn oracle_outliers_are_discarded() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		setup_oracle();
+		for (id, value) in [(1, 100), (2, 200), (3, 104)] {
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(id)), value));
+		}
+
+		TemplateModule::on_finalize(1);
+		// 200 is more than 10% off the median of 104.
+		assert_eq!(TemplateModule::something(TemplateModule::oracle_account()), Some(102));
+		System::assert_last_event(
+			Event::RoundFinalized { round: 0, something: 102, submissions: 3, outliers: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn direct_mode_can_be_restored() {
+	ExtBuilder::default().build_and_execute(|| {
+		setup_oracle();
+		assert_ok!(TemplateModule::set_mode(RuntimeOrigin::root(), WriteMode::Direct));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_eq!(TemplateModule::something(account(1)), Some(42));
+	});
+}
+
+#[test]
+fn writes_are_recorded_in_the_history() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(account(1))));
+		assert_ok!(TemplateModule::force_set_something(RuntimeOrigin::root(), account(2), 7));
+		// Clearing a value is not a write.
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(account(1))));
+
+		assert_eq!(
+			TemplateModule::history(),
+			vec![
+				HistoryItem { block_number: 1, who: account(1), something: 42 },
+				HistoryItem { block_number: 2, who: account(1), something: 43 },
+				HistoryItem { block_number: 2, who: account(2), something: 7 },
+			]
+		);
+	});
+}
+
+#[test]
+fn history_keeps_the_most_recent_writes() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		for value in 0..5 {
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), value));
+		}
+
+		let values: Vec<u32> =
+			TemplateModule::history().iter().map(|item| item.something).collect();
+		assert_eq!(values, vec![2, 3, 4]);
+		assert_eq!(HistoryCount::<Test>::get(), 5);
+		assert_eq!(History::<Test>::iter_keys().count(), 3);
+	});
+}
+
+#[test]
+fn paused_pallet_rejects_user_calls() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_noop!(TemplateModule::pause(RuntimeOrigin::signed(account(1))), BadOrigin);
+
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Paused.into());
+		assert!(Paused::<Test>::get());
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 43),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::Paused.with_weight(<() as WeightInfo>::cause_error_failed())
+		);
+		assert_noop!(
+			TemplateModule::clear_something(RuntimeOrigin::signed(account(1))),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::set_entry(RuntimeOrigin::signed(account(1)), key(b"a"), value(b"")),
+			Error::<Test>::Paused
+		);
+		// The admin origin is not affected.
+		assert_ok!(TemplateModule::force_set_something(RuntimeOrigin::root(), account(1), 7));
+		// Neither is the second instance.
+		assert_ok!(SecondTemplateModule::do_something(RuntimeOrigin::signed(account(1)), 43));
+
+		assert_ok!(TemplateModule::unpause(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Unpaused.into());
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 43));
+	});
+}
+
+#[test]
+fn paused_calls_are_kept_out_of_the_pool() {
+	ExtBuilder::default().build_and_execute(|| {
+		let validate = |call: RuntimeCall| {
+			ValidateTemplateCall::<Test>::new().validate(&account(1), &call, &Default::default(), 0)
+		};
+		let write = RuntimeCall::TemplateModule(crate::Call::do_something { something: 42 });
+		let unpause = RuntimeCall::TemplateModule(crate::Call::unpause {});
+
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		assert_eq!(validate(write), InvalidTransaction::Custom(PAUSED).into());
+		assert_ok!(validate(unpause));
+
+		// Signed offchain submissions are held back as well.
+		let public = generate_worker_key();
+		let payload = SomethingPayload { block_number: 0, something: 42, public };
+		let signature = SignedPayload::<Test>::sign::<TemplateAuthId>(&payload).unwrap();
+		let call = crate::Call::submit_something_unsigned { payload, signature };
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Custom(PAUSED).into()
+		);
+	});
+}
+
+#[test]
+fn events_are_indexed_by_account_and_key() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let account_topic = |id| topics::account::<BlakeTwo256, _>(&account(id));
+		let key_topic = topics::key::<BlakeTwo256>(b"a");
+
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(account(1)), 42));
+		assert_ok!(TemplateModule::set_entry(
+			RuntimeOrigin::signed(account(2)),
+			key(b"a"),
+			value(b"b")
+		));
+		assert_ok!(TemplateModule::set_mode(RuntimeOrigin::root(), WriteMode::Oracle));
+
+		let events = System::events();
+		let index_of = |event: Event<Test>| {
+			events.iter().position(|record| record.event == event.into()).unwrap() as u32
+		};
+		let stored = index_of(Event::SomethingStored { something: 42, who: account(1) });
+		let set = index_of(Event::EntrySet { who: account(2), key: key(b"a"), value: value(b"b") });
+		assert_eq!(events[stored as usize].topics, vec![account_topic(1)]);
+		assert_eq!(events[set as usize].topics, vec![account_topic(2), key_topic]);
+		assert!(events.last().unwrap().topics.is_empty());
+
+		assert_eq!(System::event_topics(account_topic(1)), vec![(1, stored)]);
+		assert_eq!(System::event_topics(account_topic(2)), vec![(1, set)]);
+		assert_eq!(System::event_topics(key_topic), vec![(1, set)]);
+		// Topics are not shared with other pallets indexing the same account.
+		assert!(System::event_topics(BlakeTwo256::hash_of(&account(1))).is_empty());
 	});
 }
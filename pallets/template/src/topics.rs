@@ -0,0 +1,23 @@
+//! Topics that pallet-template events are indexed by in `frame_system::EventTopics`.
+//!
+//! Topics are shared by all instances of the pallet and prefixed so they do not collide with the
+//! topics other pallets index events by.
+
+use codec::Encode;
+use sp_runtime::traits::Hash;
+
+/// Prefix of the topics of events concerning an account.
+const ACCOUNT_PREFIX: [u8; 16] = *b"template:account";
+
+/// Prefix of the topics of events concerning a registry key.
+const KEY_PREFIX: [u8; 12] = *b"template:key";
+
+/// The topic of events concerning `who`.
+pub fn account<H: Hash, AccountId: Encode>(who: &AccountId) -> H::Output {
+	H::hash_of(&(ACCOUNT_PREFIX, who))
+}
+
+/// The topic of events concerning the registry key `key`.
+pub fn key<H: Hash>(key: &[u8]) -> H::Output {
+	H::hash_of(&(KEY_PREFIX, key))
+}
@@ -0,0 +1,95 @@
+//! A signed extension that keeps calls bound to fail out of the transaction pool.
+
+use crate::{Call, Config, Pallet, Paused};
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::marker::PhantomData;
+
+/// The custom `InvalidTransaction` code of calls rejected because the sender is rate limited.
+pub const RATE_LIMITED: u8 = 0;
+/// The custom `InvalidTransaction` code of calls rejected because user calls are paused.
+pub const PAUSED: u8 = 1;
+
+/// Reject user calls while the pallet is paused, and calls that write a value from senders that
+/// reached `MaxWritesPerPeriod` in the current period.
+///
+/// Both are also enforced when the call is dispatched; this only keeps such calls out of the
+/// transaction pool, so they do not burn fees.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct ValidateTemplateCall<T: Config<I> + Send + Sync, I: 'static = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I> + Send + Sync, I: 'static> ValidateTemplateCall<T, I> {
+	/// Create a new instance of the extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static> Default for ValidateTemplateCall<T, I> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static> sp_std::fmt::Debug for ValidateTemplateCall<T, I> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ValidateTemplateCall")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> SignedExtension
+	for ValidateTemplateCall<T, I>
+where
+	T::RuntimeCall: IsSubType<Call<T, I>>,
+{
+	const IDENTIFIER: &'static str = "ValidateTemplateCall";
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let Some(call) = call.is_sub_type() else { return Ok(ValidTransaction::default()) };
+		if Pallet::<T, I>::is_user_call(call) && <Paused<T, I>>::get() {
+			return InvalidTransaction::Custom(PAUSED).into()
+		}
+		if Pallet::<T, I>::is_rate_limited(call) && !Pallet::<T, I>::can_write(who) {
+			return InvalidTransaction::Custom(RATE_LIMITED).into()
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
@@ -0,0 +1,16 @@
+//! Runtime API definition for pallet-validator-set.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Read the validators of the current session without computing storage keys by hand.
+	pub trait ValidatorSetApi {
+		/// The indices of the validators disabled for the rest of the current session.
+		///
+		/// The indices are into the validators of the session, which are also the indices of the
+		/// authorities of the consensus pallets it drives, such as Aura.
+		fn disabled_validators() -> Vec<u32>;
+	}
+}
@@ -0,0 +1,73 @@
+//! Benchmarking setup for pallet-validator-set
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ValidatorSet;
+use frame_benchmarking::v2::*;
+use frame_support::{pallet_prelude::Get, traits::EnsureOrigin, BoundedVec};
+use sp_std::vec::Vec;
+
+/// Make `n` accounts validators, returning them.
+fn fill_validators<T: Config>(n: u32) -> Vec<T::AccountId> {
+	let validators: Vec<T::AccountId> = (0..n).map(|i| account("validator", i, 0)).collect();
+	Validators::<T>::put(BoundedVec::try_from(validators.clone()).expect("within the bound; qed"));
+	validators
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn add_validator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// The new validator is checked against all others.
+		fill_validators::<T>(T::MaxValidators::get() - 1);
+		let who: T::AccountId = account("new", 0, 0);
+		#[block]
+		{
+			ValidatorSet::<T>::add_validator(origin, who.clone())?;
+		}
+
+		assert!(Validators::<T>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_validator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// The removed validator is the last one searched.
+		let who = fill_validators::<T>(T::MaxValidators::get())
+			.pop()
+			.expect("`MaxValidators` is at least `MinValidators`, which is not zero; qed");
+		#[block]
+		{
+			ValidatorSet::<T>::remove_validator(origin, who.clone())?;
+		}
+
+		assert!(!Validators::<T>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn disable_validator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// The disabled validator is the last one searched.
+		let who = pallet_session::Pallet::<T>::validators()
+			.pop()
+			.ok_or(BenchmarkError::Stop("the session has no validators"))?;
+		#[block]
+		{
+			ValidatorSet::<T>::disable_validator(origin, who)?;
+		}
+
+		assert!(!pallet_session::Pallet::<T>::disabled_validators().is_empty());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(ValidatorSet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,295 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A validator set managed by a privileged origin.
+//!
+//! The set is handed to `pallet_session` as its `SessionManager`, so additions and removals take
+//! effect at session boundaries: a change made during session `n` is planned when session `n`
+//! ends and becomes active when session `n + 1` ends. Validators must register their session keys
+//! with `session.setKeys` before they can author blocks.
+//!
+//! As an `OnOffenceHandler`, the pallet disables offenders for the rest of the session and removes
+//! them from the set, as long as that leaves at least `MinValidators` enabled and in the set
+//! respectively. `AddRemoveOrigin` can also disable a validator, e.g. one that went offline, until
+//! the session ends. When the runtime uses the session pallet as Aura's `DisabledValidators`, blocks
+//! authored by disabled validators are rejected; their slots are not reassigned and stay empty.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_runtime::{traits::Zero, Perbill};
+use sp_staking::{
+	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+	SessionIndex,
+};
+use sp_std::vec::Vec;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_session::Config<ValidatorId = <Self as frame_system::Config>::AccountId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Type representing the weight of this pallet.
+		type WeightInfo: WeightInfo;
+		/// The origin allowed to add and remove validators.
+		type AddRemoveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of validators.
+		#[pallet::constant]
+		type MaxValidators: Get<u32>;
+		/// The minimum number of validators, below which removals are rejected so the chain
+		/// keeps producing and finalizing blocks. Must not be zero.
+		#[pallet::constant]
+		type MinValidators: Get<u32>;
+	}
+
+	/// The validators to be handed to the session pallet at the next session boundary.
+	#[pallet::storage]
+	#[pallet::getter(fn validators)]
+	pub type Validators<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxValidators>, ValueQuery>;
+
+	/// Whether `Validators` changed since it was last handed to the session pallet.
+	#[pallet::storage]
+	pub type ValidatorsChanged<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The validators of the first sessions.
+		pub initial_validators: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { initial_validators: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			assert!(
+				self.initial_validators.len() >= T::MinValidators::get() as usize,
+				"fewer initial validators than `MinValidators`"
+			);
+			let validators =
+				BoundedVec::<_, T::MaxValidators>::try_from(self.initial_validators.clone())
+					.expect("more initial validators than `MaxValidators`");
+			<Validators<T>>::put(validators);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A validator was added and will be active from the session after next. [who]
+		ValidatorAdded { who: T::AccountId },
+		/// A validator was removed and will be inactive from the session after next. [who]
+		ValidatorRemoved { who: T::AccountId },
+		/// A validator was disabled for the rest of the session. [who]
+		ValidatorDisabled { who: T::AccountId },
+		/// An offending validator was disabled and removed, and will be inactive from the session
+		/// after next. [who]
+		OffenderRemoved { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already a validator.
+		AlreadyValidator,
+		/// The account is not a validator.
+		NotValidator,
+		/// Adding the validator would exceed `MaxValidators`.
+		TooManyValidators,
+		/// Removing the validator would leave fewer than `MinValidators`.
+		TooFewValidators,
+		/// The account is not an enabled validator of the current session.
+		NotActive,
+		/// Disabling the validator would leave fewer than `MinValidators` enabled.
+		TooFewEnabled,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add `who` to the validator set, from the session after next.
+		///
+		/// The origin must be `AddRemoveOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_validator())]
+		pub fn add_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AddRemoveOrigin::ensure_origin(origin)?;
+
+			<Validators<T>>::try_mutate(|validators| {
+				ensure!(!validators.contains(&who), Error::<T>::AlreadyValidator);
+				validators.try_push(who.clone()).map_err(|_| Error::<T>::TooManyValidators)
+			})?;
+			<ValidatorsChanged<T>>::put(true);
+
+			Self::deposit_event(Event::ValidatorAdded { who });
+			Ok(())
+		}
+
+		/// Remove `who` from the validator set, from the session after next.
+		///
+		/// The origin must be `AddRemoveOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_validator())]
+		pub fn remove_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AddRemoveOrigin::ensure_origin(origin)?;
+
+			<Validators<T>>::try_mutate(|validators| {
+				let index = validators
+					.iter()
+					.position(|validator| *validator == who)
+					.ok_or(Error::<T>::NotValidator)?;
+				ensure!(
+					validators.len() > T::MinValidators::get() as usize,
+					Error::<T>::TooFewValidators
+				);
+				validators.remove(index);
+				Ok::<_, DispatchError>(())
+			})?;
+			<ValidatorsChanged<T>>::put(true);
+
+			Self::deposit_event(Event::ValidatorRemoved { who });
+			Ok(())
+		}
+
+		/// Disable `who` for the rest of the current session, so its Aura slots stay empty.
+		/// At least `MinValidators` are kept enabled.
+		///
+		/// The origin must be `AddRemoveOrigin`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::disable_validator())]
+		pub fn disable_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AddRemoveOrigin::ensure_origin(origin)?;
+
+			let index = pallet_session::Pallet::<T>::validators()
+				.iter()
+				.position(|validator| *validator == who)
+				.ok_or(Error::<T>::NotActive)? as u32;
+			ensure!(
+				!pallet_session::Pallet::<T>::disabled_validators().contains(&index),
+				Error::<T>::NotActive
+			);
+			ensure!(Self::can_disable(), Error::<T>::TooFewEnabled);
+			pallet_session::Pallet::<T>::disable_index(index);
+
+			Self::deposit_event(Event::ValidatorDisabled { who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether one more validator of the current session can be disabled while keeping at least
+	/// `MinValidators`, and never none, enabled.
+	fn can_disable() -> bool {
+		let validators = pallet_session::Pallet::<T>::validators().len();
+		let enabled =
+			validators.saturating_sub(pallet_session::Pallet::<T>::disabled_validators().len());
+		enabled > T::MinValidators::get().max(1) as usize
+	}
+
+	/// Remove the offender `who` from the validator set, unless that would leave fewer than
+	/// `MinValidators`.
+	fn remove_offender(who: &T::AccountId) {
+		let removed = Validators::<T>::mutate(|validators| {
+			if validators.len() <= T::MinValidators::get() as usize {
+				return false
+			}
+			let index = validators.iter().position(|validator| validator == who);
+			index.map(|index| validators.remove(index)).is_some()
+		});
+		if removed {
+			ValidatorsChanged::<T>::put(true);
+			Self::deposit_event(Event::OffenderRemoved { who: who.clone() });
+		}
+	}
+}
+
+impl<T: Config> pallet_session::SessionManager<T::AccountId> for Pallet<T> {
+	fn new_session(_new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+		ValidatorsChanged::<T>::take().then(|| Validators::<T>::get().into_inner())
+	}
+
+	fn new_session_genesis(_new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+		Some(Validators::<T>::get().into_inner())
+	}
+
+	fn end_session(_end_index: SessionIndex) {}
+
+	fn start_session(_start_index: SessionIndex) {}
+}
+
+/// Identifies each validator by its account, so the session pallet's historical module can note
+/// the validators of every session and equivocations can be proven against them.
+impl<T: Config> pallet_session::historical::SessionManager<T::AccountId, T::AccountId>
+	for Pallet<T>
+{
+	fn new_session(new_index: SessionIndex) -> Option<Vec<(T::AccountId, T::AccountId)>> {
+		<Self as pallet_session::SessionManager<_>>::new_session(new_index)
+			.map(|validators| validators.into_iter().map(|v| (v.clone(), v)).collect())
+	}
+
+	fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<(T::AccountId, T::AccountId)>> {
+		<Self as pallet_session::SessionManager<_>>::new_session_genesis(new_index)
+			.map(|validators| validators.into_iter().map(|v| (v.clone(), v)).collect())
+	}
+
+	fn end_session(end_index: SessionIndex) {
+		<Self as pallet_session::SessionManager<_>>::end_session(end_index)
+	}
+
+	fn start_session(start_index: SessionIndex) {
+		<Self as pallet_session::SessionManager<_>>::start_session(start_index)
+	}
+}
+
+impl<T: Config, FullIdentification>
+	OnOffenceHandler<T::AccountId, (T::AccountId, FullIdentification), Weight> for Pallet<T>
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<T::AccountId, (T::AccountId, FullIdentification)>],
+		slash_fraction: &[Perbill],
+		_session: SessionIndex,
+		disable_strategy: DisableStrategy,
+	) -> Weight {
+		for (details, fraction) in offenders.iter().zip(slash_fraction) {
+			let disable = match disable_strategy {
+				DisableStrategy::Never => false,
+				DisableStrategy::WhenSlashed => !fraction.is_zero(),
+				DisableStrategy::Always => true,
+			};
+			if disable {
+				let (who, _) = &details.offender;
+				if Self::can_disable() {
+					pallet_session::Pallet::<T>::disable(who);
+				}
+				Self::remove_offender(who);
+			}
+		}
+		// The session's disabled validators and validators, and this pallet's validators.
+		T::DbWeight::get().reads_writes(3, 3).saturating_mul(offenders.len() as u64)
+	}
+}
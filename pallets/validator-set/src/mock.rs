@@ -0,0 +1,107 @@
+use crate as pallet_validator_set;
+use frame_support::traits::{ConstU16, ConstU32, ConstU64, GenesisBuild};
+use frame_system::EnsureRoot;
+use sp_core::{crypto::KeyTypeId, H256};
+use sp_runtime::{
+	key_types::DUMMY,
+	testing::{Header, UintAuthorityId},
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		ValidatorSet: pallet_validator_set,
+		Session: pallet_session,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_validator_set::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type AddRemoveOrigin = EnsureRoot<u64>;
+	type MaxValidators = ConstU32<4>;
+	type MinValidators = ConstU32<2>;
+}
+
+/// Session handler for the single dummy key of `UintAuthorityId`.
+pub struct TestSessionHandler;
+
+impl pallet_session::SessionHandler<u64> for TestSessionHandler {
+	const KEY_TYPE_IDS: &'static [KeyTypeId] = &[DUMMY];
+
+	fn on_genesis_session<Ks: OpaqueKeys>(_validators: &[(u64, Ks)]) {}
+
+	fn on_new_session<Ks: OpaqueKeys>(
+		_changed: bool,
+		_validators: &[(u64, Ks)],
+		_queued_validators: &[(u64, Ks)],
+	) {
+	}
+
+	fn on_disabled(_validator_index: u32) {}
+}
+
+impl pallet_session::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = u64;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pallet_session::PeriodicSessions<ConstU64<1>, ConstU64<0>>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<ConstU64<1>, ConstU64<0>>;
+	type SessionManager = ValidatorSet;
+	type SessionHandler = TestSessionHandler;
+	type Keys = UintAuthorityId;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime, with validators 1, 2 and 3.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let initial_validators = vec![1, 2, 3];
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_validator_set::GenesisConfig::<Test> { initial_validators: initial_validators.clone() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	pallet_session::GenesisConfig::<Test> {
+		keys: initial_validators.into_iter().map(|v| (v, v, UintAuthorityId(v))).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
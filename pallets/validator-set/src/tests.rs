@@ -0,0 +1,206 @@
+use crate::{mock::*, Error, Event, ValidatorsChanged};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, Perbill};
+use sp_staking::offence::{DisableStrategy, OffenceDetails, OnOffenceHandler};
+
+/// Register session keys for `who`, giving it an account first.
+fn set_keys(who: u64) {
+	System::inc_providers(&who);
+	assert_ok!(Session::set_keys(RuntimeOrigin::signed(who), UintAuthorityId(who), Vec::new()));
+}
+
+/// Report an offence of each of `offenders` with the given slash fraction.
+fn report(offenders: &[u64], fraction: Perbill, strategy: DisableStrategy) {
+	let details: Vec<_> = offenders
+		.iter()
+		.map(|offender| OffenceDetails { offender: (*offender, ()), reporters: Vec::new() })
+		.collect();
+	let fractions = vec![fraction; offenders.len()];
+	<ValidatorSet as OnOffenceHandler<u64, (u64, ()), _>>::on_offence(
+		&details,
+		&fractions,
+		Session::current_index(),
+		strategy,
+	);
+}
+
+#[test]
+fn genesis_validators_start_the_first_session() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ValidatorSet::validators(), vec![1, 2, 3]);
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+		assert!(!ValidatorsChanged::<Test>::get());
+	});
+}
+
+#[test]
+fn only_the_add_remove_origin_changes_validators() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(ValidatorSet::add_validator(RuntimeOrigin::signed(1), 4), BadOrigin);
+		assert_noop!(ValidatorSet::remove_validator(RuntimeOrigin::signed(1), 3), BadOrigin);
+		assert_noop!(ValidatorSet::disable_validator(RuntimeOrigin::signed(1), 3), BadOrigin);
+	});
+}
+
+#[test]
+fn adding_validators_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ValidatorSet::add_validator(RuntimeOrigin::root(), 1),
+			Error::<Test>::AlreadyValidator
+		);
+
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		System::assert_last_event(Event::ValidatorAdded { who: 4 }.into());
+		assert_eq!(ValidatorSet::validators(), vec![1, 2, 3, 4]);
+		assert!(ValidatorsChanged::<Test>::get());
+
+		assert_noop!(
+			ValidatorSet::add_validator(RuntimeOrigin::root(), 5),
+			Error::<Test>::TooManyValidators
+		);
+	});
+}
+
+#[test]
+fn removing_validators_keeps_the_minimum() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::root(), 4),
+			Error::<Test>::NotValidator
+		);
+
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::ValidatorRemoved { who: 2 }.into());
+		assert_eq!(ValidatorSet::validators(), vec![1, 3]);
+		assert!(ValidatorsChanged::<Test>::get());
+
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::root(), 3),
+			Error::<Test>::TooFewValidators
+		);
+	});
+}
+
+#[test]
+fn changes_take_effect_at_session_boundaries() {
+	new_test_ext().execute_with(|| {
+		set_keys(4);
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 1));
+
+		// The change is planned at the end of the current session...
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+		assert_eq!(Session::queued_keys().iter().map(|(v, _)| *v).collect::<Vec<_>>(), [2, 3, 4]);
+		assert!(!ValidatorsChanged::<Test>::get());
+
+		// ...and active from the next one.
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![2, 3, 4]);
+
+		// Sessions without changes keep the set.
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![2, 3, 4]);
+	});
+}
+
+#[test]
+fn validators_without_keys_are_not_activated() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+
+		Session::rotate_session();
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn offenders_are_disabled_and_removed() {
+	new_test_ext().execute_with(|| {
+		// Offences that are not slashed do not disable.
+		report(&[1], Perbill::zero(), DisableStrategy::WhenSlashed);
+		report(&[1], Perbill::one(), DisableStrategy::Never);
+		assert!(Session::disabled_validators().is_empty());
+		assert_eq!(ValidatorSet::validators(), vec![1, 2, 3]);
+
+		report(&[2], Perbill::from_percent(10), DisableStrategy::WhenSlashed);
+		System::assert_last_event(Event::OffenderRemoved { who: 2 }.into());
+		assert_eq!(Session::disabled_validators(), vec![1]);
+		assert_eq!(ValidatorSet::validators(), vec![1, 3]);
+		assert!(ValidatorsChanged::<Test>::get());
+
+		// The last `MinValidators` are kept, and kept enabled.
+		report(&[3], Perbill::zero(), DisableStrategy::Always);
+		assert_eq!(Session::disabled_validators(), vec![1]);
+		assert_eq!(ValidatorSet::validators(), vec![1, 3]);
+
+		// Disabling lasts for the session, removal from the one after next.
+		Session::rotate_session();
+		assert!(Session::disabled_validators().is_empty());
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 3]);
+	});
+}
+
+#[test]
+fn offenders_are_disabled_while_min_validators_stay_enabled() {
+	new_test_ext().execute_with(|| {
+		set_keys(4);
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		Session::rotate_session();
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3, 4]);
+
+		// Disabling 1 and 2 leaves `MinValidators` enabled.
+		report(&[1, 2], Perbill::one(), DisableStrategy::Always);
+		assert_eq!(Session::disabled_validators(), vec![0, 1]);
+
+		// Disabling 3 would not, and with `MinValidators` left in the set it is not removed either.
+		report(&[3], Perbill::one(), DisableStrategy::Always);
+		assert_eq!(Session::disabled_validators(), vec![0, 1]);
+		assert_eq!(ValidatorSet::validators(), vec![3, 4]);
+	});
+}
+
+#[test]
+fn validators_are_disabled_for_the_session() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ValidatorSet::disable_validator(RuntimeOrigin::root(), 4),
+			Error::<Test>::NotActive
+		);
+
+		assert_ok!(ValidatorSet::disable_validator(RuntimeOrigin::root(), 3));
+		System::assert_last_event(Event::ValidatorDisabled { who: 3 }.into());
+		assert_eq!(Session::disabled_validators(), vec![2]);
+		assert_noop!(
+			ValidatorSet::disable_validator(RuntimeOrigin::root(), 3),
+			Error::<Test>::NotActive
+		);
+		// The validator stays in the set.
+		assert_eq!(ValidatorSet::validators(), vec![1, 2, 3]);
+		assert!(!ValidatorsChanged::<Test>::get());
+
+		Session::rotate_session();
+		assert!(Session::disabled_validators().is_empty());
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn disabling_keeps_min_validators_enabled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ValidatorSet::disable_validator(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			ValidatorSet::disable_validator(RuntimeOrigin::root(), 2),
+			Error::<Test>::TooFewEnabled
+		);
+		assert_eq!(Session::disabled_validators(), vec![0]);
+
+		// The next session enables everyone again.
+		Session::rotate_session();
+		assert_ok!(ValidatorSet::disable_validator(RuntimeOrigin::root(), 2));
+	});
+}
@@ -6,6 +6,9 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+pub mod migrations;
+
+use codec::Encode;
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -13,10 +16,11 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, Extrinsic as ExtrinsicT,
+		IdentifyAccount, NumberFor, One, OpaqueKeys, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, MultiSignature, SaturatedConversion,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -25,9 +29,12 @@ use sp_version::RuntimeVersion;
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime,
+	instances::Instance1,
+	parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains, EitherOfDiverse,
+		EqualPrivilegeOnly, KeyOwnerProofSystem, Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -35,9 +42,10 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -101,12 +109,15 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	//   the compatible custom types. It must be increased for every runtime upgrade, otherwise
+	//   `set_code` rejects the new runtime and its `Migrations` never run.
+	spec_version: 104,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Must be increased whenever the way transactions are encoded or signed changes, e.g. the
+	//   `SignedExtra` or a pallet or call index, so that signed transactions are invalidated.
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -153,7 +164,7 @@ parameter_types! {
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = SafeMode;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -205,19 +216,75 @@ impl frame_system::Config for Runtime {
 
 impl pallet_aura::Config for Runtime {
 	type AuthorityId = AuraId;
-	type DisabledValidators = ();
+	/// Blocks authored by validators disabled for the session, by an offence or by the validator
+	/// set's `AddRemoveOrigin`, are rejected. Aura does not reassign their slots, which stay empty.
+	type DisabledValidators = Session;
 	type MaxAuthorities = ConstU32<32>;
 }
 
+impl pallet_authorship::Config for Runtime {
+	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+	type EventHandler = ();
+}
+
+parameter_types! {
+	/// Equivocations can be reported for a week of sessions.
+	pub const MaxSetIdSessionEntries: u64 = 7 * 24;
+	/// Equivocation reports are valid for as long as their session can be reported.
+	pub const ReportLongevity: u64 = MaxSetIdSessionEntries::get() * SessionPeriod::get() as u64;
+}
+
 impl pallet_grandpa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 
 	type WeightInfo = ();
 	type MaxAuthorities = ConstU32<32>;
-	type MaxSetIdSessionEntries = ConstU64<0>;
+	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
+
+	type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, GrandpaId)>>::Proof;
+	type EquivocationReportSystem =
+		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
+}
 
-	type KeyOwnerProof = sp_core::Void;
-	type EquivocationReportSystem = ();
+parameter_types! {
+	/// Sessions last an hour, so validator set changes take effect within two hours.
+	pub const SessionPeriod: BlockNumber = HOURS;
+	pub const SessionOffset: BlockNumber = 0;
+}
+
+impl pallet_session::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	/// The validators of each session are taken from the validator set pallet, noting each
+	/// session's validators so equivocations can be proven against them.
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorSet>;
+	type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = opaque::SessionKeys;
+	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = AccountId;
+	type FullIdentificationOf = ConvertInto;
+}
+
+impl pallet_offences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
+	/// Offenders are disabled and removed from the validator set.
+	type OnOffenceHandler = ValidatorSet;
+}
+
+impl pallet_validator_set::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_validator_set::weights::SubstrateWeight<Runtime>;
+	type AddRemoveOrigin = EnsureRootOrTwoThirdsCouncil;
+	/// At most as many validators as Aura and GRANDPA accept authorities.
+	type MaxValidators = ConstU32<32>;
+	type MinValidators = ConstU32<1>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -233,7 +300,7 @@ pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -267,10 +334,286 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub MaxCouncilProposalWeight: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
+}
+
+/// The council, a collective whose members are managed by `CouncilMembership`.
+pub type CouncilCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = ConstU32<100>;
+	type MaxMembers = ConstU32<100>;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	/// Members are set through `CouncilMembership`.
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxCouncilProposalWeight;
+}
+
+/// Root, or at least two thirds of the council.
+pub type EnsureRootOrTwoThirdsCouncil = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+>;
+
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRootOrTwoThirdsCouncil;
+	type RemoveOrigin = EnsureRootOrTwoThirdsCouncil;
+	type SwapOrigin = EnsureRootOrTwoThirdsCouncil;
+	type ResetOrigin = EnsureRootOrTwoThirdsCouncil;
+	type PrimeOrigin = EnsureRootOrTwoThirdsCouncil;
+	type MembershipInitialized = Council;
+	type MembershipChanged = Council;
+	type MaxMembers = ConstU32<100>;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+	pub const PreimageByteDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+}
+
+parameter_types! {
+	pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+	pub const VotingPeriod: BlockNumber = 7 * DAYS;
+	pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+	pub const EnactmentPeriod: BlockNumber = DAYS;
+	pub const CooloffPeriod: BlockNumber = 7 * DAYS;
+	pub const MinimumDeposit: Balance = 10_000 * EXISTENTIAL_DEPOSIT;
+}
+
+/// Referenda are proposed by token holders or the council and, once passed, dispatched as Root
+/// by the scheduler.
+impl pallet_democracy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type EnactmentPeriod = EnactmentPeriod;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type VoteLockingPeriod = EnactmentPeriod;
+	type MinimumDeposit = MinimumDeposit;
+	/// Half of the council can propose an external referendum.
+	type ExternalOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+	/// Three quarters of the council can propose an external majority-carries referendum.
+	type ExternalMajorityOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>;
+	/// The whole council can propose an external negative-turnout-bias referendum.
+	type ExternalDefaultOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	/// Two thirds of the council can shorten the voting period of external proposals.
+	type FastTrackOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	type InstantOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type InstantAllowed = ConstBool<true>;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	type CancellationOrigin = EnsureRootOrTwoThirdsCouncil;
+	type CancelProposalOrigin = EnsureRootOrTwoThirdsCouncil;
+	type BlacklistOrigin = EnsureRoot<AccountId>;
+	/// Any council member can veto an external proposal once.
+	type VetoOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+	type CooloffPeriod = CooloffPeriod;
+	type Slash = ();
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type MaxVotes = ConstU32<100>;
+	type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
+	type MaxProposals = ConstU32<100>;
+	type Preimages = Preimage;
+	type MaxDeposits = ConstU32<100>;
+	type MaxBlacklisted = ConstU32<100>;
+}
+
+/// The calls that can be dispatched while safe mode is active: inherents, equivocation reports,
+/// `sudo`, council motions and leaving safe mode.
+///
+/// Calls dispatched as Root, e.g. by `sudo` or an enacted referendum, are never filtered, so none
+/// of the Root-only `System` calls need to be listed.
+pub struct SafeModeWhitelist;
+
+impl Contains<RuntimeCall> for SafeModeWhitelist {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Timestamp(_) |
+				RuntimeCall::Grandpa(_) |
+				RuntimeCall::Sudo(_) |
+				RuntimeCall::Council(_) |
+				RuntimeCall::SafeMode(_)
+		)
+	}
+}
+
+impl pallet_safe_mode::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_safe_mode::weights::SubstrateWeight<Runtime>;
+	type ForceOrigin = EnsureRootOrTwoThirdsCouncil;
+	type WhitelistedCalls = SafeModeWhitelist;
+	type MaxDuration = ConstU32<{ 2 * HOURS }>;
+}
+
+parameter_types! {
+	/// The deposit reserved for each value stored in pallet-template.
+	pub const TemplateSomethingDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	/// The deposit reserved for each commitment in pallet-template, slashed if not revealed.
+	pub const TemplateCommitDeposit: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+	pub const TemplatePalletId: PalletId = PalletId(*b"py/tmpl0");
+	pub const SecondTemplatePalletId: PalletId = PalletId(*b"py/tmpl1");
+	/// Oracle submissions further than this from the median are discarded.
+	pub const TemplateMaxDeviation: Permill = Permill::from_percent(5);
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type AuthorityId = pallet_template::crypto::TemplateAuthId;
+	type MaxAuthorities = ConstU32<32>;
+	type OffchainInterval = ConstU32<10>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type SomethingDeposit = TemplateSomethingDeposit;
+	type SomethingTtl = ConstU32<{ 7 * DAYS }>;
+	type MaxExpiringPerBlock = ConstU32<256>;
+	type MaxScheduledPerBlock = ConstU32<64>;
+	type OverflowPolicy = pallet_template::overflow::Checked;
+	type MaxIncrementStep = ConstU32<100>;
+	type Period = ConstU32<{ 10 * MINUTES }>;
+	type MaxWritesPerPeriod = ConstU32<20>;
+	type MaxKeyLen = ConstU32<64>;
+	type MaxValueLen = ConstU32<256>;
+	type MaxEntries = ConstU32<100>;
+	type CommitPhaseLength = ConstU32<{ 10 * MINUTES }>;
+	type RevealPhaseLength = ConstU32<{ 10 * MINUTES }>;
+	type CommitDeposit = TemplateCommitDeposit;
+	type MaxCommits = ConstU32<100>;
+	type Slashed = ();
+	type PalletId = TemplatePalletId;
+	type MaxFeeders = ConstU32<32>;
+	type MaxDeviation = TemplateMaxDeviation;
+	type HistoryDepth = ConstU32<100>;
+}
+
+/// A second, independent instance of pallet-template with its own storage and events.
+impl pallet_template::Config<Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	/// The instance runs the same code as `TemplateModule`, so it shares its weights until the
+	/// `SecondTemplateModule` benchmarks are run against this runtime.
+	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type AuthorityId = pallet_template::crypto::TemplateAuthId;
+	type MaxAuthorities = ConstU32<32>;
+	type OffchainInterval = ConstU32<10>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type SomethingDeposit = TemplateSomethingDeposit;
+	type SomethingTtl = ConstU32<{ 7 * DAYS }>;
+	type MaxExpiringPerBlock = ConstU32<256>;
+	type MaxScheduledPerBlock = ConstU32<64>;
+	type OverflowPolicy = pallet_template::overflow::Saturating;
+	type MaxIncrementStep = ConstU32<100>;
+	type Period = ConstU32<{ 10 * MINUTES }>;
+	type MaxWritesPerPeriod = ConstU32<20>;
+	type MaxKeyLen = ConstU32<64>;
+	type MaxValueLen = ConstU32<256>;
+	type MaxEntries = ConstU32<100>;
+	type CommitPhaseLength = ConstU32<{ 10 * MINUTES }>;
+	type RevealPhaseLength = ConstU32<{ 10 * MINUTES }>;
+	type CommitDeposit = TemplateCommitDeposit;
+	type MaxCommits = ConstU32<100>;
+	type Slashed = ();
+	type PalletId = SecondTemplatePalletId;
+	type MaxFeeders = ConstU32<32>;
+	type MaxDeviation = TemplateMaxDeviation;
+	type HistoryDepth = ConstU32<100>;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(RuntimeCall, <UncheckedExtrinsic as ExtrinsicT>::SignaturePayload)> {
+		// Use the longest mortality period the block hash count allows.
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+			pallet_template::ValidateTemplateCall::<Runtime>::new(),
+			pallet_template::ValidateTemplateCall::<Runtime, Instance1>::new(),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (Address::Id(account), signature, extra)))
+	}
+}
+
+parameter_types! {
+	/// The account that keeps the old global `Something` value when migrating to per-account
+	/// values.
+	pub TemplateMigrationOwner: Option<AccountId> = Sudo::key();
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -281,15 +624,33 @@ construct_runtime!(
 		NodeBlock = opaque::Block,
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
-		System: frame_system,
-		Timestamp: pallet_timestamp,
-		Aura: pallet_aura,
-		Grandpa: pallet_grandpa,
-		Balances: pallet_balances,
-		TransactionPayment: pallet_transaction_payment,
-		Sudo: pallet_sudo,
+		// Pallets keep the index they were added with, so that the calls and storage of a running
+		// chain keep their encoding; new pallets take the next free index.
+		System: frame_system = 0,
+		Timestamp: pallet_timestamp = 1,
+		// Hooks run in declaration order: the validator set must be built before the session, which
+		// must be initialized before the consensus pallets so new authorities are in place when
+		// they run.
+		ValidatorSet: pallet_validator_set = 9,
+		Session: pallet_session = 10,
+		Historical: pallet_session::historical = 11,
+		Offences: pallet_offences = 12,
+		Aura: pallet_aura = 2,
+		Grandpa: pallet_grandpa = 3,
+		Authorship: pallet_authorship = 13,
+		Balances: pallet_balances = 4,
+		TransactionPayment: pallet_transaction_payment = 5,
+		Sudo: pallet_sudo = 6,
+		SafeMode: pallet_safe_mode = 14,
+		// On-chain governance, which replaces sudo once `RemoveSudoKey` ran.
+		Council: pallet_collective<Instance1> = 15,
+		CouncilMembership: pallet_membership<Instance1> = 16,
+		Preimage: pallet_preimage = 17,
+		Scheduler: pallet_scheduler = 18,
+		Democracy: pallet_democracy = 19,
 		// Include the custom logic from the pallet-template in the runtime.
-		TemplateModule: pallet_template,
+		TemplateModule: pallet_template = 7,
+		SecondTemplateModule: pallet_template<Instance1> = 8,
 	}
 );
 
@@ -309,6 +670,8 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_template::ValidateTemplateCall<Runtime>,
+	pallet_template::ValidateTemplateCall<Runtime, Instance1>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -316,6 +679,24 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade, oldest first.
+///
+/// Each migration checks the on-chain storage version of the pallet it migrates, or whether its
+/// work is already done, so they can stay listed here until every chain has upgraded past them.
+/// `RemoveSudoKey` is the exception and is only meant for a single release. Run
+/// `try-runtime on-runtime-upgrade` to exercise their pre- and post-upgrade checks against live
+/// state.
+pub type Migrations = (
+	pallet_template::migrations::v1::MigrateToV1<Runtime, TemplateMigrationOwner>,
+	// Only sets the storage version of the newly added instance.
+	pallet_template::migrations::v1::MigrateToV1<Runtime, (), Instance1>,
+	// Takes the validators of chains started without a validator set from their authorities.
+	migrations::SeedValidatorSet<Runtime>,
+	// Hands over to on-chain governance once the council has members besides the sudo key. Only
+	// runs in the upgrade to spec version 104: remove it from this list in the next release.
+	migrations::RemoveSudoKey<Runtime, CouncilMembership, ConstU32<104>>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -323,6 +704,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -337,6 +719,14 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_template, SecondTemplateModule]
+		[pallet_validator_set, ValidatorSet]
+		[pallet_safe_mode, SafeMode]
+		[pallet_collective, Council]
+		[pallet_membership, CouncilMembership]
+		[pallet_preimage, Preimage]
+		[pallet_scheduler, Scheduler]
+		[pallet_democracy, Democracy]
 	);
 }
 
@@ -438,23 +828,24 @@ impl_runtime_apis! {
 		}
 
 		fn submit_report_equivocation_unsigned_extrinsic(
-			_equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+			equivocation_proof: sp_consensus_grandpa::EquivocationProof<
 				<Block as BlockT>::Hash,
 				NumberFor<Block>,
 			>,
-			_key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+			key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
 		) -> Option<()> {
-			None
+			let key_owner_proof = key_owner_proof.decode()?;
+
+			Grandpa::submit_unsigned_equivocation_report(equivocation_proof, key_owner_proof)
 		}
 
 		fn generate_key_ownership_proof(
 			_set_id: sp_consensus_grandpa::SetId,
-			_authority_id: GrandpaId,
+			authority_id: GrandpaId,
 		) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-			// NOTE: this is the only implementation possible since we've
-			// defined our key owner proof type as a bottom type (i.e. a type
-			// with no values).
-			None
+			Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+				.map(|proof| proof.encode())
+				.map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
 		}
 	}
 
@@ -485,6 +876,45 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_validator_set_runtime_api::ValidatorSetApi<Block> for Runtime {
+		fn disabled_validators() -> Vec<u32> {
+			Session::disabled_validators()
+		}
+	}
+
+	impl pallet_template_runtime_api::TemplateApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_value(who: AccountId) -> Option<u32> {
+			TemplateModule::something(who)
+		}
+
+		fn history() -> Vec<(BlockNumber, AccountId, u32)> {
+			TemplateModule::history()
+				.into_iter()
+				.map(|item| (item.block_number, item.who, item.something))
+				.collect()
+		}
+
+		fn get_instance_value(instance: u8, who: AccountId) -> Option<u32> {
+			match instance {
+				0 => TemplateModule::something(who),
+				1 => SecondTemplateModule::something(who),
+				_ => None,
+			}
+		}
+
+		fn instance_history(instance: u8) -> Vec<(BlockNumber, AccountId, u32)> {
+			let history = match instance {
+				0 => TemplateModule::history(),
+				1 => SecondTemplateModule::history(),
+				_ => Vec::new(),
+			};
+			history
+				.into_iter()
+				.map(|item| (item.block_number, item.who, item.something))
+				.collect()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -575,10 +1005,360 @@ impl_runtime_apis! {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::traits::WhitelistedStorageKeys;
-	use sp_core::hexdisplay::HexDisplay;
+	use frame_support::{
+		assert_noop, assert_ok,
+		dispatch::GetDispatchInfo,
+		traits::{OnRuntimeUpgrade, PalletInfoAccess, WhitelistedStorageKeys},
+	};
+	use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+	use sp_consensus_grandpa::{Equivocation, EquivocationProof, RoundNumber, SetId};
+	use sp_core::{hexdisplay::HexDisplay, H256};
+	use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+	use sp_runtime::{
+		traits::{Dispatchable, Hash, ValidateUnsigned},
+		Digest, DigestItem,
+	};
 	use std::collections::HashSet;
 
+	/// The validators of the test chain with their GRANDPA keys.
+	const VALIDATORS: [(Sr25519Keyring, Ed25519Keyring); 2] = [
+		(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+		(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+	];
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let keys = VALIDATORS
+			.iter()
+			.map(|(account, grandpa)| {
+				let keys = opaque::SessionKeys {
+					aura: account.public().into(),
+					grandpa: grandpa.public().into(),
+				};
+				(account.to_account_id(), account.to_account_id(), keys)
+			})
+			.collect();
+		let storage = GenesisConfig {
+			validator_set: ValidatorSetConfig {
+				initial_validators: VALIDATORS.iter().map(|(v, _)| v.to_account_id()).collect(),
+			},
+			session: SessionConfig { keys },
+			sudo: SudoConfig { key: Some(VALIDATORS[0].0.to_account_id()) },
+			..Default::default()
+		}
+		.build_storage()
+		.unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	/// A proof of `offender` signing both prevotes, given as `(target hash, target number)`, in
+	/// `round` of `set_id`.
+	fn equivocation_proof(
+		set_id: SetId,
+		round: RoundNumber,
+		offender: Ed25519Keyring,
+		votes: [(H256, BlockNumber); 2],
+	) -> EquivocationProof<H256, BlockNumber> {
+		let signed_prevote = |(target_hash, target_number): (H256, BlockNumber)| {
+			let prevote = finality_grandpa::Prevote { target_hash, target_number };
+			let message = finality_grandpa::Message::Prevote(prevote.clone());
+			let payload = sp_consensus_grandpa::localized_payload(round, set_id, &message);
+			let signature: sp_consensus_grandpa::AuthoritySignature =
+				offender.sign(&payload).into();
+			(prevote, signature)
+		};
+
+		EquivocationProof::new(
+			set_id,
+			Equivocation::Prevote(finality_grandpa::Equivocation {
+				round_number: round,
+				identity: offender.public().into(),
+				first: signed_prevote(votes[0]),
+				second: signed_prevote(votes[1]),
+			}),
+		)
+	}
+
+	/// Author a block at `slot` on top of the current one.
+	fn author_block(slot: u64) {
+		let number = System::block_number() + 1;
+		let pre_digest = DigestItem::PreRuntime(AURA_ENGINE_ID, Slot::from(slot).encode());
+		let header = generic::Header::new(
+			number,
+			Default::default(),
+			Default::default(),
+			System::parent_hash(),
+			Digest { logs: vec![pre_digest] },
+		);
+		Executive::initialize_block(&header);
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), slot * SLOT_DURATION));
+		Executive::finalize_block();
+	}
+
+	#[test]
+	fn blocks_are_authored_with_a_disabled_authority() {
+		new_test_ext().execute_with(|| {
+			let bob = VALIDATORS[1].0.to_account_id();
+			assert_ok!(ValidatorSet::disable_validator(RuntimeOrigin::root(), bob));
+
+			// Alice authors the even slots, while Bob's odd ones stay empty.
+			for slot in [2, 4, 6] {
+				author_block(slot);
+				assert_eq!(*Aura::current_slot(), slot);
+			}
+			assert_eq!(System::block_number(), 4);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "is disabled and should not be attempting to author blocks")]
+	fn disabled_authorities_cannot_author_blocks() {
+		new_test_ext().execute_with(|| {
+			let bob = VALIDATORS[1].0.to_account_id();
+			assert_ok!(ValidatorSet::disable_validator(RuntimeOrigin::root(), bob));
+
+			author_block(3);
+		});
+	}
+
+	#[test]
+	fn grandpa_equivocations_disable_the_offender() {
+		new_test_ext().execute_with(|| {
+			let (offender, offender_key) = VALIDATORS[0];
+			let set_id = Grandpa::current_set_id();
+			let key_owner_proof = || {
+				Historical::prove((
+					sp_consensus_grandpa::KEY_TYPE,
+					GrandpaId::from(offender_key.public()),
+				))
+				.expect("the offender is a validator of the current session; qed")
+			};
+
+			// Voting twice for the same block is not an equivocation.
+			let same_votes = [(H256::repeat_byte(1), 1); 2];
+			assert_noop!(
+				Grandpa::report_equivocation_unsigned(
+					RuntimeOrigin::none(),
+					Box::new(equivocation_proof(set_id, 1, offender_key, same_votes)),
+					key_owner_proof(),
+				),
+				pallet_grandpa::Error::<Runtime>::InvalidEquivocationProof
+			);
+
+			let votes = [(H256::repeat_byte(1), 1), (H256::repeat_byte(2), 1)];
+			let proof = equivocation_proof(set_id, 1, offender_key, votes);
+			let call = pallet_grandpa::Call::report_equivocation_unsigned {
+				equivocation_proof: Box::new(proof.clone()),
+				key_owner_proof: key_owner_proof(),
+			};
+			assert_ok!(Grandpa::validate_unsigned(TransactionSource::Local, &call));
+			assert_ok!(Grandpa::report_equivocation_unsigned(
+				RuntimeOrigin::none(),
+				Box::new(proof.clone()),
+				key_owner_proof(),
+			));
+
+			assert_eq!(Session::disabled_validators(), vec![0]);
+			assert_eq!(ValidatorSet::validators(), vec![VALIDATORS[1].0.to_account_id()]);
+			System::assert_has_event(
+				pallet_validator_set::Event::OffenderRemoved { who: offender.to_account_id() }
+					.into(),
+			);
+
+			// The same offence is only punished once.
+			assert_noop!(
+				Grandpa::report_equivocation_unsigned(
+					RuntimeOrigin::none(),
+					Box::new(proof),
+					key_owner_proof(),
+				),
+				pallet_grandpa::Error::<Runtime>::DuplicateOffenceReport
+			);
+		});
+	}
+
+	#[test]
+	fn historical_roots_are_noted_for_every_session() {
+		new_test_ext().execute_with(|| {
+			let charlie = Sr25519Keyring::Charlie.to_account_id();
+			let (root, count) = Historical::historical_root(1).expect("noted at genesis; qed");
+			assert_eq!(count, VALIDATORS.len() as u32);
+
+			// An unchanged set keeps the root of the previous session.
+			Session::rotate_session();
+			assert_eq!(Historical::historical_root(2), Some((root, count)));
+
+			assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), charlie));
+			Session::rotate_session();
+			let (new_root, new_count) =
+				Historical::historical_root(3).expect("noted when the session was planned; qed");
+			assert_eq!(new_count, count + 1);
+			assert_ne!(new_root, root);
+		});
+	}
+
+	#[test]
+	fn safe_mode_keeps_authoring_blocks_but_filters_user_calls() {
+		new_test_ext().execute_with(|| {
+			let bob = VALIDATORS[1].0.to_account_id();
+			let transfer =
+				RuntimeCall::Balances(BalancesCall::transfer { dest: bob.into(), value: 1 });
+			let set_timestamp = RuntimeCall::Timestamp(TimestampCall::set { now: 0 });
+			let exit = RuntimeCall::SafeMode(pallet_safe_mode::Call::exit {});
+			let sudo_exit = RuntimeCall::Sudo(pallet_sudo::Call::sudo { call: Box::new(exit) });
+			assert!(SafeMode::contains(&transfer));
+
+			assert_ok!(SafeMode::enter(RuntimeOrigin::root(), 2));
+			assert!(!SafeMode::contains(&transfer));
+			assert!(SafeMode::contains(&set_timestamp));
+			assert!(SafeMode::contains(&sudo_exit));
+
+			// Remarks are not needed to recover the chain and could otherwise fill its blocks.
+			let remark = RuntimeCall::System(SystemCall::remark { remark: vec![1] });
+			assert!(!SafeMode::contains(&remark));
+			assert_noop!(
+				remark.dispatch(RuntimeOrigin::signed(VALIDATORS[0].0.to_account_id())),
+				frame_system::Error::<Runtime>::CallFiltered
+			);
+
+			author_block(2);
+			assert!(SafeMode::is_entered());
+			author_block(3);
+			assert!(!SafeMode::is_entered());
+			assert!(SafeMode::contains(&transfer));
+		});
+	}
+
+	#[test]
+	fn two_thirds_of_the_council_can_enter_safe_mode() {
+		new_test_ext().execute_with(|| {
+			let [alice, bob, charlie] =
+				[Sr25519Keyring::Alice, Sr25519Keyring::Bob, Sr25519Keyring::Charlie]
+					.map(|keyring| keyring.to_account_id());
+			assert_ok!(CouncilMembership::reset_members(
+				RuntimeOrigin::root(),
+				vec![alice.clone(), bob.clone(), charlie]
+			));
+
+			let call = RuntimeCall::SafeMode(pallet_safe_mode::Call::enter { duration: HOURS });
+			let (hash, length) = (BlakeTwo256::hash_of(&call), call.encoded_size() as u32);
+			assert_noop!(
+				SafeMode::enter(RuntimeOrigin::signed(alice.clone()), HOURS),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Council::propose(
+				RuntimeOrigin::signed(alice.clone()),
+				2,
+				Box::new(call.clone()),
+				length
+			));
+			assert_ok!(Council::vote(RuntimeOrigin::signed(bob), hash, 0, true));
+			assert_ok!(Council::close(
+				RuntimeOrigin::signed(alice),
+				hash,
+				0,
+				call.get_dispatch_info().weight,
+				length
+			));
+
+			assert!(SafeMode::is_entered());
+		});
+	}
+
+	#[test]
+	fn validator_set_is_seeded_from_the_authorities_of_older_chains() {
+		new_test_ext().execute_with(|| {
+			let validators = ValidatorSet::validators();
+			let queued_keys = Session::queued_keys();
+			let consumers: Vec<_> = validators.iter().map(System::consumers).collect();
+
+			// A chain started before the validator set and session pallets only has authorities.
+			for pallet in [ValidatorSet::name(), Session::name(), Historical::name()] {
+				let _ = frame_support::storage::unhashed::clear_prefix(
+					&sp_core::hashing::twox_128(pallet.as_bytes()),
+					None,
+					None,
+				);
+			}
+			validators.iter().for_each(System::dec_consumers);
+			let alice_key = GrandpaId::from(VALIDATORS[0].1.public());
+			assert!(Historical::prove((sp_consensus_grandpa::KEY_TYPE, alice_key)).is_none());
+
+			migrations::SeedValidatorSet::<Runtime>::on_runtime_upgrade();
+			assert_eq!(ValidatorSet::validators(), validators);
+			assert_eq!(Session::validators(), validators.to_vec());
+			assert_eq!(Session::queued_keys(), queued_keys);
+			assert_eq!(validators.iter().map(System::consumers).collect::<Vec<_>>(), consumers);
+			for (_, grandpa) in VALIDATORS {
+				let key = GrandpaId::from(grandpa.public());
+				assert!(Historical::prove((sp_consensus_grandpa::KEY_TYPE, key)).is_some());
+			}
+
+			// Offences in the upgrade session and the next cannot be proven: they have no root.
+			let alice_key = (sp_consensus_grandpa::KEY_TYPE, alice_key);
+			let proof = Historical::prove(alice_key.clone()).expect("seeded above; qed");
+			assert_eq!(Historical::historical_root(0), None);
+			assert!(Historical::check_proof(alice_key.clone(), proof).is_none());
+			Session::rotate_session();
+			assert_eq!(Historical::historical_root(1), None);
+			let (_, count) = Historical::historical_root(2).expect("noted when planned; qed");
+			assert_eq!(count, VALIDATORS.len() as u32);
+
+			// They can from the session planned after the upgrade on.
+			Session::rotate_session();
+			let proof = Historical::prove(alice_key.clone()).expect("still a validator; qed");
+			assert!(Historical::check_proof(alice_key, proof).is_some());
+
+			// Running it again is a no-op.
+			let bob = VALIDATORS[1].0.to_account_id();
+			assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), bob));
+			migrations::SeedValidatorSet::<Runtime>::on_runtime_upgrade();
+			assert_eq!(ValidatorSet::validators(), validators[..1].to_vec());
+		});
+	}
+
+	#[test]
+	fn sudo_key_is_only_removed_once_the_council_has_other_members() {
+		new_test_ext().execute_with(|| {
+			type RemoveSudoKey =
+				migrations::RemoveSudoKey<Runtime, CouncilMembership, ConstU32<104>>;
+			let [alice, bob] = VALIDATORS.map(|(keyring, _)| keyring.to_account_id());
+			let remark = Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }));
+
+			RemoveSudoKey::upgrade_to(104);
+			assert_eq!(Sudo::key(), Some(alice.clone()));
+
+			// A council of the sudo key alone, as on the development chain, does not replace it.
+			assert_ok!(CouncilMembership::reset_members(
+				RuntimeOrigin::root(),
+				vec![alice.clone()]
+			));
+			RemoveSudoKey::upgrade_to(104);
+			assert_eq!(Sudo::key(), Some(alice.clone()));
+
+			assert_ok!(CouncilMembership::reset_members(
+				RuntimeOrigin::root(),
+				vec![alice.clone(), bob]
+			));
+			// Only the upgrade the migration ships in removes the key.
+			RemoveSudoKey::upgrade_to(103);
+			RemoveSudoKey::upgrade_to(105);
+			assert_eq!(Sudo::key(), Some(alice.clone()));
+
+			RemoveSudoKey::upgrade_to(104);
+			assert_eq!(Sudo::key(), None);
+			assert_noop!(
+				Sudo::sudo(RuntimeOrigin::signed(alice), remark),
+				pallet_sudo::Error::<Runtime>::RequireSudo
+			);
+
+			// Running it again is a no-op.
+			RemoveSudoKey::upgrade_to(104);
+			assert_eq!(Sudo::key(), None);
+		});
+	}
+
 	#[test]
 	fn check_whitelist() {
 		let whitelist: HashSet<String> = AllPalletsWithSystem::whitelisted_storage_keys()
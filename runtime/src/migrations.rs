@@ -0,0 +1,264 @@
+//! Runtime-level storage migrations.
+//!
+//! Pallet storage migrations live with their pallet, see e.g.
+//! [`pallet_template::migrations`]. This module holds the migrations that change how the runtime
+//! itself is governed or that set up pallets added to a running chain.
+
+use crate::{opaque::SessionKeys, AccountId};
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{OnRuntimeUpgrade, SortedMembers},
+};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_core::{crypto::KeyTypeId, sr25519};
+use sp_runtime::{
+	traits::{IdentifyAccount, OpaqueKeys},
+	MultiSigner,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+const LOG_TARGET: &str = "runtime::migrations";
+
+/// The sudo key, which `pallet_sudo` only lets the current key holder change.
+mod sudo {
+	use super::*;
+
+	#[storage_alias]
+	pub type Key<T: pallet_sudo::Config> =
+		StorageValue<pallet_sudo::Pallet<T>, <T as frame_system::Config>::AccountId>;
+}
+
+/// The storage of `pallet_session` that is only written by the pallet itself.
+mod session {
+	use super::*;
+
+	#[storage_alias]
+	pub type Validators<T: pallet_session::Config> = StorageValue<
+		pallet_session::Pallet<T>,
+		Vec<<T as pallet_session::Config>::ValidatorId>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub type QueuedKeys<T: pallet_session::Config> = StorageValue<
+		pallet_session::Pallet<T>,
+		Vec<(<T as pallet_session::Config>::ValidatorId, <T as pallet_session::Config>::Keys)>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub type NextKeys<T: pallet_session::Config> = StorageMap<
+		pallet_session::Pallet<T>,
+		Twox64Concat,
+		<T as pallet_session::Config>::ValidatorId,
+		<T as pallet_session::Config>::Keys,
+	>;
+
+	#[storage_alias]
+	pub type KeyOwner<T: pallet_session::Config> = StorageMap<
+		pallet_session::Pallet<T>,
+		Twox64Concat,
+		(KeyTypeId, Vec<u8>),
+		<T as pallet_session::Config>::ValidatorId,
+	>;
+}
+
+/// Seeds the validator set and the session keys from the Aura and GRANDPA authorities, for chains
+/// started before `pallet_validator_set` and `pallet_session` were added to the runtime.
+///
+/// The `n`th Aura authority is paired with the `n`th GRANDPA authority, as the chain spec lists
+/// them, and its account is the one of its sr25519 key. The next session rotation hands the seeded
+/// set to the session pallet like any change to it, which also notes the first historical root for
+/// the session it plans. The session the upgrade happens in and the one after it have no root, so
+/// offences committed in them cannot be proven.
+/// Chains whose validator set or session is already initialized, including every chain started
+/// since, are left untouched.
+pub struct SeedValidatorSet<T>(PhantomData<T>);
+
+impl<T> SeedValidatorSet<T>
+where
+	T: pallet_validator_set::Config
+		+ frame_system::Config<AccountId = AccountId>
+		+ pallet_aura::Config<AuthorityId = AuraId>
+		+ pallet_grandpa::Config
+		+ pallet_session::Config<Keys = SessionKeys>,
+{
+	/// The validators with their session keys, if the chain has yet to be seeded.
+	fn validators_to_seed() -> Option<Vec<(AccountId, SessionKeys)>> {
+		if !pallet_validator_set::Validators::<T>::get().is_empty() ||
+			!session::Validators::<T>::get().is_empty()
+		{
+			return None
+		}
+
+		let aura = pallet_aura::Pallet::<T>::authorities().into_inner();
+		let grandpa = pallet_grandpa::Pallet::<T>::grandpa_authorities();
+		if aura.is_empty() || aura.len() != grandpa.len() {
+			log::warn!(
+				target: LOG_TARGET,
+				"not seeding the validator set from {} Aura and {} GRANDPA authorities",
+				aura.len(),
+				grandpa.len(),
+			);
+			return None
+		}
+
+		let validators = aura
+			.into_iter()
+			.zip(grandpa)
+			.map(|(aura, (grandpa, _))| {
+				let public: &sr25519::Public = aura.as_ref();
+				(MultiSigner::from(*public).into_account(), SessionKeys { aura, grandpa })
+			})
+			.collect();
+		Some(validators)
+	}
+}
+
+impl<T> OnRuntimeUpgrade for SeedValidatorSet<T>
+where
+	T: pallet_validator_set::Config
+		+ frame_system::Config<AccountId = AccountId>
+		+ pallet_aura::Config<AuthorityId = AuraId>
+		+ pallet_grandpa::Config
+		+ pallet_session::Config<Keys = SessionKeys>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let validators_and_keys = match Self::validators_to_seed() {
+			Some(validators_and_keys) => validators_and_keys,
+			None => return T::DbWeight::get().reads(4),
+		};
+		let validators: Vec<_> = validators_and_keys.iter().map(|(who, _)| who.clone()).collect();
+		let count = validators.len() as u64;
+		let bounded = match BoundedVec::<_, T::MaxValidators>::try_from(validators.clone()) {
+			Ok(bounded) => bounded,
+			Err(_) => {
+				log::warn!(target: LOG_TARGET, "not seeding the validator set: too many authorities");
+				return T::DbWeight::get().reads(4)
+			},
+		};
+
+		for (who, keys) in &validators_and_keys {
+			// As `pallet_session` does for the keys it is given at genesis.
+			if frame_system::Pallet::<T>::inc_consumers_without_limit(who).is_err() {
+				let _ = frame_system::Pallet::<T>::inc_providers(who);
+				let _ = frame_system::Pallet::<T>::inc_consumers_without_limit(who);
+			}
+			for id in SessionKeys::key_ids() {
+				session::KeyOwner::<T>::insert((*id, keys.get_raw(*id).to_vec()), who);
+			}
+			session::NextKeys::<T>::insert(who, keys);
+		}
+		session::Validators::<T>::put(validators);
+		session::QueuedKeys::<T>::put(validators_and_keys);
+		pallet_validator_set::Validators::<T>::put(bounded);
+		pallet_validator_set::ValidatorsChanged::<T>::put(true);
+
+		log::info!(target: LOG_TARGET, "seeded the validator set with {} validators", count);
+		T::DbWeight::get().reads_writes(4 + count, 4 + 4 * count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok(Self::validators_to_seed().is_some().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let seeded: bool = Decode::decode(&mut &state[..])
+			.map_err(|_| "SeedValidatorSet: failed to decode pre-upgrade state")?;
+
+		let validators = pallet_validator_set::Validators::<T>::get().into_inner();
+		ensure!(!seeded || !validators.is_empty(), "the validator set was not seeded");
+		ensure!(
+			!seeded || session::Validators::<T>::get() == validators,
+			"the session validators differ from the validator set"
+		);
+		Ok(())
+	}
+}
+
+/// Removes the sudo key, handing every privileged action over to on-chain governance.
+///
+/// Only the upgrade to spec version `SpecVersion` removes the key, so the migration must be
+/// dropped from the runtime's migrations in the release after that one. The key is also kept
+/// while `Council` has no member besides the sudo key itself, as on a development chain, so a
+/// chain can never be upgraded into a state where neither sudo nor the council can act, nor have
+/// its single privileged account swapped for a council of one. Once removed, `pallet_sudo` rejects
+/// every call and the pallet can be dropped from the runtime in a later upgrade.
+pub struct RemoveSudoKey<T, Council, SpecVersion>(PhantomData<(T, Council, SpecVersion)>);
+
+impl<T, Council, SpecVersion> RemoveSudoKey<T, Council, SpecVersion>
+where
+	T: pallet_sudo::Config,
+	Council: SortedMembers<T::AccountId>,
+	SpecVersion: Get<u32>,
+{
+	/// The version of the runtime being upgraded to.
+	fn spec_version() -> u32 {
+		<T as frame_system::Config>::Version::get().spec_version
+	}
+
+	/// Whether the upgrade to `spec_version` should remove the key.
+	fn should_remove(spec_version: u32) -> bool {
+		if spec_version != SpecVersion::get() {
+			log::warn!(
+				target: LOG_TARGET,
+				"keeping the sudo key: `RemoveSudoKey` only runs in spec version {}, not {}",
+				SpecVersion::get(),
+				spec_version,
+			);
+			return false
+		}
+
+		let key = match sudo::Key::<T>::get() {
+			Some(key) => key,
+			None => return false,
+		};
+		if Council::sorted_members().iter().all(|member| *member == key) {
+			log::warn!(
+				target: LOG_TARGET,
+				"keeping the sudo key: the council has no members besides the sudo key",
+			);
+			return false
+		}
+		true
+	}
+
+	/// Run the upgrade to `spec_version`, removing the key if it should.
+	pub(crate) fn upgrade_to(spec_version: u32) -> Weight {
+		if !Self::should_remove(spec_version) {
+			return T::DbWeight::get().reads(2)
+		}
+
+		sudo::Key::<T>::kill();
+		log::info!(target: LOG_TARGET, "removed the sudo key");
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+}
+
+impl<T, Council, SpecVersion> OnRuntimeUpgrade for RemoveSudoKey<T, Council, SpecVersion>
+where
+	T: pallet_sudo::Config,
+	Council: SortedMembers<T::AccountId>,
+	SpecVersion: Get<u32>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		Self::upgrade_to(Self::spec_version())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok(Self::should_remove(Self::spec_version()).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let should_remove: bool = Decode::decode(&mut &state[..])
+			.map_err(|_| "RemoveSudoKey: failed to decode pre-upgrade state")?;
+
+		ensure!(!should_remove || !sudo::Key::<T>::exists(), "the sudo key was not removed");
+		Ok(())
+	}
+}